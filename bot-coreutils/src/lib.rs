@@ -1,9 +1,12 @@
 #[cfg(test)]
 mod tests;
 
+pub mod lrc;
 pub mod process;
+pub mod selection;
 pub mod shuffle;
 pub mod string;
+pub mod time;
 /// Utilities to quickly check strings that represent urls
 pub mod url;
 
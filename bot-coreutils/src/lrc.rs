@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+/// A line of time-synced lyrics
+#[derive(Clone, Debug, PartialEq)]
+pub struct LrcLine {
+    pub time: Duration,
+    pub text: String,
+}
+
+/// Parses lyrics in the LRC format returning the lines sorted by their time.
+/// Lines without timestamps and metadata tags are ignored
+/// Example
+/// ```
+/// use bot_coreutils::lrc::parse_lrc;
+/// use std::time::Duration;
+///
+/// let lines = parse_lrc("[ar:Artist]\n[00:01.50]First\n[00:03.00]Second");
+/// assert_eq!(lines.len(), 2);
+/// assert_eq!(lines[0].time, Duration::from_millis(1500));
+/// assert_eq!(lines[1].text, "Second");
+/// ```
+pub fn parse_lrc(content: &str) -> Vec<LrcLine> {
+    let mut offset = 0i64;
+    let mut lines = Vec::new();
+
+    for line in content.lines() {
+        let mut rest = line.trim();
+        let mut times = Vec::new();
+
+        while let Some(tag) = rest.strip_prefix('[') {
+            let end = match tag.find(']') {
+                Some(end) => end,
+                None => break,
+            };
+            let tag_content = &tag[..end];
+            rest = &tag[end + 1..];
+
+            if let Some(time) = parse_timestamp(tag_content) {
+                times.push(time);
+            } else if let Some(value) = tag_content.strip_prefix("offset:") {
+                offset = value.trim().parse().unwrap_or(0);
+            }
+        }
+        let text = rest.trim().to_string();
+
+        for time in times {
+            lines.push((time, text.clone()));
+        }
+    }
+    let mut lines: Vec<LrcLine> = lines
+        .into_iter()
+        .map(|(time, text)| LrcLine {
+            // a positive offset shows the lyrics earlier
+            time: Duration::from_millis((time as i64 - offset).max(0) as u64),
+            text,
+        })
+        .collect();
+    lines.sort_by_key(|l| l.time);
+
+    lines
+}
+
+/// Returns the index of the line that is sung at the given position
+pub fn line_at(lines: &[LrcLine], position: Duration) -> Option<usize> {
+    let count = lines.iter().take_while(|l| l.time <= position).count();
+
+    count.checked_sub(1)
+}
+
+/// Parses a timestamp in the format `mm:ss[.xx]` to milliseconds
+fn parse_timestamp(value: &str) -> Option<u64> {
+    let (minutes, seconds) = value.split_once(':')?;
+    let minutes = minutes.parse::<u64>().ok()?;
+    let (seconds, fraction) = seconds.split_once('.').unwrap_or((seconds, "0"));
+    let seconds = seconds.parse::<u64>().ok()?;
+
+    if fraction.is_empty() || fraction.len() > 3 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let millis = format!("{:0<3}", fraction).parse::<u64>().ok()?;
+
+    Some((minutes * 60 + seconds) * 1000 + millis)
+}
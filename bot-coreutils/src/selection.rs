@@ -0,0 +1,78 @@
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+/// The maximum number of indices a selection can contain
+static MAX_SELECTION_SIZE: usize = 10_000;
+
+/// Parses a selection of indices in the format `3-7,10`.
+/// The returned indices are sorted and unique
+/// Example
+/// ```
+/// use bot_coreutils::selection::parse_selection;
+///
+/// assert_eq!(parse_selection("3-5,10"), Some(vec![3, 4, 5, 10]));
+/// assert_eq!(parse_selection("2"), Some(vec![2]));
+/// assert_eq!(parse_selection("a-b"), None);
+/// ```
+pub fn parse_selection(value: &str) -> Option<Vec<usize>> {
+    let mut indices = Vec::new();
+
+    for part in value.split(',').map(str::trim) {
+        if let Some((start, end)) = part.split_once('-') {
+            let start = start.trim().parse::<usize>().ok()?;
+            let end = end.trim().parse::<usize>().ok()?;
+            let (start, end) = (start.min(end), start.max(end));
+
+            if end - start >= MAX_SELECTION_SIZE {
+                return None;
+            }
+            indices.extend(start..=end);
+        } else {
+            indices.push(part.parse::<usize>().ok()?);
+        }
+        if indices.len() > MAX_SELECTION_SIZE {
+            return None;
+        }
+    }
+    indices.sort_unstable();
+    indices.dedup();
+
+    Some(indices)
+}
+
+/// Removes the entries at the given indices ignoring the ones out of bounds.
+/// Returns the removed entries in their previous order
+pub fn remove_indices<T>(entries: &mut VecDeque<T>, indices: &[usize]) -> Vec<T> {
+    let indices: HashSet<&usize> = indices.iter().collect();
+    let mut removed = Vec::new();
+    let mut kept = VecDeque::with_capacity(entries.len());
+
+    for (i, entry) in entries.drain(..).enumerate() {
+        if indices.contains(&i) {
+            removed.push(entry);
+        } else {
+            kept.push_back(entry);
+        }
+    }
+    *entries = kept;
+
+    removed
+}
+
+/// Removes all entries that have the same key as an earlier entry.
+/// Returns the removed entries in their previous order
+pub fn dedupe_by_key<T, K, F>(entries: &mut VecDeque<T>, key: F) -> Vec<T>
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+{
+    let mut seen = HashSet::new();
+    let duplicates: Vec<usize> = entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| !seen.insert(key(entry)))
+        .map(|(i, _)| i)
+        .collect();
+
+    remove_indices(entries, &duplicates)
+}
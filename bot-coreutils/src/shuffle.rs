@@ -1,5 +1,9 @@
-use rand::Rng;
-use std::collections::VecDeque;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
 
 pub trait Shuffle {
     fn shuffle(&mut self);
@@ -18,3 +22,151 @@ impl<T> Shuffle for VecDeque<T> {
         }
     }
 }
+
+pub trait SmartShuffle<T> {
+    /// Shuffles the entries so that entries with the same key
+    /// are spread evenly instead of being grouped together
+    fn smart_shuffle<K: Hash + Eq, F: Fn(&T) -> K>(&mut self, key: F);
+
+    /// Smart shuffle that always returns the same order for a seed
+    fn smart_shuffle_seeded<K: Hash + Eq, F: Fn(&T) -> K>(&mut self, key: F, seed: u64);
+
+    /// Shuffles the entries so that entries with the same primary key are spread evenly
+    /// and entries with the same secondary key are spread among the ones of a primary key
+    fn smart_shuffle_nested<K1, K2, F1, F2>(&mut self, primary: F1, secondary: F2)
+    where
+        K1: Hash + Eq,
+        K2: Hash + Eq,
+        F1: Fn(&T) -> K1,
+        F2: Fn(&T) -> K2;
+
+    /// Nested smart shuffle that always returns the same order for a seed
+    fn smart_shuffle_nested_seeded<K1, K2, F1, F2>(
+        &mut self,
+        primary: F1,
+        secondary: F2,
+        seed: u64,
+    ) where
+        K1: Hash + Eq,
+        K2: Hash + Eq,
+        F1: Fn(&T) -> K1,
+        F2: Fn(&T) -> K2;
+}
+
+impl<T> SmartShuffle<T> for VecDeque<T> {
+    fn smart_shuffle<K: Hash + Eq, F: Fn(&T) -> K>(&mut self, key: F) {
+        smart_shuffle_with_rng(self, key, &mut rand::thread_rng())
+    }
+
+    fn smart_shuffle_seeded<K: Hash + Eq, F: Fn(&T) -> K>(&mut self, key: F, seed: u64) {
+        smart_shuffle_with_rng(self, key, &mut StdRng::seed_from_u64(seed))
+    }
+
+    fn smart_shuffle_nested<K1, K2, F1, F2>(&mut self, primary: F1, secondary: F2)
+    where
+        K1: Hash + Eq,
+        K2: Hash + Eq,
+        F1: Fn(&T) -> K1,
+        F2: Fn(&T) -> K2,
+    {
+        nested_smart_shuffle_with_rng(self, primary, secondary, &mut rand::thread_rng())
+    }
+
+    fn smart_shuffle_nested_seeded<K1, K2, F1, F2>(&mut self, primary: F1, secondary: F2, seed: u64)
+    where
+        K1: Hash + Eq,
+        K2: Hash + Eq,
+        F1: Fn(&T) -> K1,
+        F2: Fn(&T) -> K2,
+    {
+        let mut rng = StdRng::seed_from_u64(seed);
+        nested_smart_shuffle_with_rng(self, primary, secondary, &mut rng)
+    }
+}
+
+/// Balanced shuffle implementation.
+/// Entries are grouped by their key and every group is shuffled.
+/// The entries of a group are then placed at equal distances
+/// starting at a random offset and all entries are sorted by their placement
+fn smart_shuffle_with_rng<T, K, F, R>(entries: &mut VecDeque<T>, key: F, rng: &mut R)
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+    R: Rng,
+{
+    let mut groups = group_by_key(entries.drain(..), key);
+
+    for group in &mut groups {
+        group.shuffle(rng);
+    }
+    entries.extend(spread_groups(groups, rng));
+}
+
+/// Nested balanced shuffle implementation.
+/// Entries are grouped by the primary key and every group is smart shuffled
+/// by the secondary key before the groups are spread like in the balanced shuffle
+fn nested_smart_shuffle_with_rng<T, K1, K2, F1, F2, R>(
+    entries: &mut VecDeque<T>,
+    primary: F1,
+    secondary: F2,
+    rng: &mut R,
+) where
+    K1: Hash + Eq,
+    K2: Hash + Eq,
+    F1: Fn(&T) -> K1,
+    F2: Fn(&T) -> K2,
+    R: Rng,
+{
+    let groups = group_by_key(entries.drain(..), primary)
+        .into_iter()
+        .map(|group| {
+            let mut group = VecDeque::from(group);
+            smart_shuffle_with_rng(&mut group, &secondary, rng);
+            group.into()
+        })
+        .collect();
+    entries.extend(spread_groups(groups, rng));
+}
+
+/// Groups the entries by their key.
+/// Groups are kept in order of appearance so that seeded shuffles are deterministic
+fn group_by_key<T, K, F, I>(entries: I, key: F) -> Vec<Vec<T>>
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+    I: IntoIterator<Item = T>,
+{
+    let mut group_indices: HashMap<K, usize> = HashMap::new();
+    let mut groups: Vec<Vec<T>> = Vec::new();
+
+    for entry in entries {
+        let index = *group_indices.entry(key(&entry)).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[index].push(entry);
+    }
+
+    groups
+}
+
+/// Places the entries of every group at equal distances starting at a random offset
+/// and returns all entries sorted by their placement
+fn spread_groups<T, R: Rng>(groups: Vec<Vec<T>>, rng: &mut R) -> Vec<T> {
+    let mut placed: Vec<(f64, T)> = Vec::new();
+
+    for group in groups {
+        let spacing = 1.0 / group.len() as f64;
+        let offset = rng.gen::<f64>() * spacing;
+
+        placed.extend(
+            group
+                .into_iter()
+                .enumerate()
+                .map(|(i, entry)| (offset + i as f64 * spacing, entry)),
+        );
+    }
+    placed.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    placed.into_iter().map(|(_, entry)| entry).collect()
+}
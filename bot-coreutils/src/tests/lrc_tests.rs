@@ -0,0 +1,51 @@
+use crate::lrc::*;
+use std::time::Duration;
+
+#[test]
+fn it_parses_lrc() {
+    let lines = parse_lrc(
+        "[ti:Title]\n[ar:Artist]\n\n[00:12.00]Line one\n[00:05.5]Intro\n[01:02.123]Line two\nno timestamp",
+    );
+    assert_eq!(
+        lines,
+        vec![
+            LrcLine {
+                time: Duration::from_millis(5500),
+                text: "Intro".to_string()
+            },
+            LrcLine {
+                time: Duration::from_secs(12),
+                text: "Line one".to_string()
+            },
+            LrcLine {
+                time: Duration::from_millis(62_123),
+                text: "Line two".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn it_parses_repeated_lrc_lines() {
+    let lines = parse_lrc("[00:10][00:30.00]Chorus\n[00:20.00]Verse");
+    let texts: Vec<&str> = lines.iter().map(|l| l.text.as_str()).collect();
+    assert_eq!(texts, vec!["Chorus", "Verse", "Chorus"]);
+    assert_eq!(lines[0].time, Duration::from_secs(10));
+}
+
+#[test]
+fn it_applies_lrc_offsets() {
+    let lines = parse_lrc("[offset:+500]\n[00:01.00]First\n[00:00.20]Zero");
+    assert_eq!(lines[0].time, Duration::from_secs(0));
+    assert_eq!(lines[1].time, Duration::from_millis(500));
+}
+
+#[test]
+fn it_finds_the_current_lrc_line() {
+    let lines = parse_lrc("[00:01.00]One\n[00:02.00]Two\n[00:03.00]Three");
+    assert_eq!(line_at(&lines, Duration::from_millis(500)), None);
+    assert_eq!(line_at(&lines, Duration::from_secs(1)), Some(0));
+    assert_eq!(line_at(&lines, Duration::from_millis(2500)), Some(1));
+    assert_eq!(line_at(&lines, Duration::from_secs(60)), Some(2));
+    assert_eq!(line_at(&[], Duration::from_secs(1)), None);
+}
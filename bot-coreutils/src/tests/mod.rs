@@ -3,3 +3,15 @@ mod url_tests;
 
 #[cfg(test)]
 mod string_tests;
+
+#[cfg(test)]
+mod time_tests;
+
+#[cfg(test)]
+mod lrc_tests;
+
+#[cfg(test)]
+mod selection_tests;
+
+#[cfg(test)]
+mod shuffle_tests;
@@ -0,0 +1,40 @@
+use crate::selection::{dedupe_by_key, parse_selection, remove_indices};
+use std::collections::VecDeque;
+
+#[test]
+fn test_parse_selection() {
+    assert_eq!(parse_selection("4"), Some(vec![4]));
+    assert_eq!(parse_selection("3-7,10"), Some(vec![3, 4, 5, 6, 7, 10]));
+    assert_eq!(parse_selection("5-3"), Some(vec![3, 4, 5]));
+    assert_eq!(parse_selection("1, 2-3, 2"), Some(vec![1, 2, 3]));
+    assert_eq!(parse_selection(""), None);
+    assert_eq!(parse_selection("1-"), None);
+    assert_eq!(parse_selection("-1"), None);
+    assert_eq!(parse_selection("0-100000000"), None);
+}
+
+#[test]
+fn test_remove_indices() {
+    let mut entries: VecDeque<usize> = (0..6).collect();
+    let removed = remove_indices(&mut entries, &[4, 1, 20]);
+
+    assert_eq!(removed, vec![1, 4]);
+    assert_eq!(entries, VecDeque::from(vec![0, 2, 3, 5]));
+}
+
+#[test]
+fn test_remove_indices_empty() {
+    let mut entries: VecDeque<usize> = (0..3).collect();
+
+    assert!(remove_indices(&mut entries, &[]).is_empty());
+    assert_eq!(entries, VecDeque::from(vec![0, 1, 2]));
+}
+
+#[test]
+fn test_dedupe_by_key() {
+    let mut entries = VecDeque::from(vec!["a", "B", "b", "c", "A", "a"]);
+    let removed = dedupe_by_key(&mut entries, |e| e.to_lowercase());
+
+    assert_eq!(removed, vec!["b", "A", "a"]);
+    assert_eq!(entries, VecDeque::from(vec!["a", "B", "c"]));
+}
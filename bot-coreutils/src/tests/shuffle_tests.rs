@@ -0,0 +1,82 @@
+use crate::shuffle::SmartShuffle;
+use std::collections::VecDeque;
+
+fn grouped_entries() -> VecDeque<(char, usize)> {
+    ('a'..='d')
+        .flat_map(|artist| (0..4).map(move |i| (artist, i)))
+        .collect()
+}
+
+#[test]
+fn test_smart_shuffle_keeps_entries() {
+    let mut entries = grouped_entries();
+    entries.smart_shuffle(|(artist, _)| *artist);
+
+    let mut sorted: Vec<(char, usize)> = entries.into_iter().collect();
+    sorted.sort();
+    assert_eq!(sorted, grouped_entries().into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_smart_shuffle_seeded_is_deterministic() {
+    let mut first = grouped_entries();
+    let mut second = grouped_entries();
+    first.smart_shuffle_seeded(|(artist, _)| *artist, 42);
+    second.smart_shuffle_seeded(|(artist, _)| *artist, 42);
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_smart_shuffle_spreads_groups() {
+    for seed in 0..100 {
+        let mut entries: VecDeque<(char, usize)> = ['a', 'b']
+            .iter()
+            .flat_map(|artist| (0..5).map(move |i| (*artist, i)))
+            .collect();
+        entries.smart_shuffle_seeded(|(artist, _)| *artist, seed);
+
+        for (previous, next) in entries.iter().zip(entries.iter().skip(1)) {
+            assert_ne!(previous.0, next.0, "seed {} produced {:?}", seed, entries);
+        }
+    }
+}
+
+#[test]
+fn test_smart_shuffle_empty() {
+    let mut entries: VecDeque<(char, usize)> = VecDeque::new();
+    entries.smart_shuffle_seeded(|(artist, _)| *artist, 1);
+
+    assert!(entries.is_empty());
+}
+
+#[test]
+fn test_smart_shuffle_nested_spreads_both_keys() {
+    for seed in 0..100 {
+        // (requester, artist, index) with two artists for each of two requesters
+        let mut entries: VecDeque<(char, char, usize)> = ['x', 'y']
+            .iter()
+            .flat_map(|requester| {
+                ['a', 'b']
+                    .iter()
+                    .flat_map(move |artist| (0..3).map(move |i| (*requester, *artist, i)))
+            })
+            .collect();
+        entries.smart_shuffle_nested_seeded(|e| e.0, |e| e.1, seed);
+
+        for (previous, next) in entries.iter().zip(entries.iter().skip(1)) {
+            assert_ne!(previous.0, next.0, "seed {} produced {:?}", seed, entries);
+        }
+        for requester in ['x', 'y'] {
+            let artists: Vec<char> = entries
+                .iter()
+                .filter(|e| e.0 == requester)
+                .map(|e| e.1)
+                .collect();
+
+            for (previous, next) in artists.iter().zip(artists.iter().skip(1)) {
+                assert_ne!(previous, next, "seed {} produced {:?}", seed, entries);
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::time::*;
+use std::time::Duration;
+
+#[test]
+fn it_parses_durations() {
+    assert_eq!(parse_duration("12"), Some(Duration::from_secs(12)));
+    assert_eq!(parse_duration("2:05"), Some(Duration::from_secs(125)));
+    assert_eq!(parse_duration("1:02:03"), Some(Duration::from_secs(3723)));
+    assert_eq!(parse_duration(" 0:30 "), Some(Duration::from_secs(30)));
+    assert_eq!(parse_duration("1:2:3:4"), None);
+    assert_eq!(parse_duration("a:30"), None);
+    assert_eq!(parse_duration(""), None);
+}
+
+#[test]
+fn it_rejects_out_of_range_durations() {
+    assert_eq!(parse_duration("90"), Some(Duration::from_secs(90)));
+    assert_eq!(parse_duration("1:99"), None);
+    assert_eq!(parse_duration("1:60:00"), None);
+    assert_eq!(parse_duration("1:00:60"), None);
+    assert_eq!(parse_duration("18446744073709551615:59"), None);
+    assert_eq!(
+        parse_duration("18446744073709551615"),
+        Some(Duration::from_secs(u64::MAX))
+    );
+}
+
+#[test]
+fn it_formats_durations() {
+    assert_eq!(format_duration(Duration::from_secs(0)), "0:00");
+    assert_eq!(format_duration(Duration::from_secs(65)), "1:05");
+    assert_eq!(format_duration(Duration::from_secs(3723)), "1:02:03");
+    assert_eq!(format_duration(Duration::from_millis(59_999)), "0:59");
+}
@@ -36,9 +36,54 @@ fn it_checks_for_video() {
     assert!(!is_video("not an url"));
 }
 
+#[test]
+fn it_checks_for_audio() {
+    assert!(is_audio("domain.com/song.mp3"));
+    assert!(is_audio("https://domain.com/song.flac?yo=someparam"));
+    assert!(is_audio("https://domain.com/song.ogg"));
+    assert!(is_audio("https://domain.com/song.wav"));
+    assert!(is_audio("https://domain.com/song.opus"));
+    assert!(!is_audio("https://domain.com"));
+    assert!(!is_audio("https://domain.com/video.mp4"));
+    assert!(!is_audio("not an url"));
+}
+
 #[test]
 fn it_checks_if_its_valid() {
     assert!(is_valid("https://domain.com"));
     assert!(!is_valid("domain.com"));
     assert!(is_valid("https://url.com/sub/sub/sub.txt"))
 }
+
+#[test]
+fn it_checks_for_remote_http() {
+    assert!(is_remote_http("https://domain.com/audio.mp3"));
+    assert!(is_remote_http("http://93.184.216.34/stream"));
+    assert!(!is_remote_http("domain.com/audio.mp3"));
+    assert!(!is_remote_http("/home/user/audio.mp3"));
+    assert!(!is_remote_http("file:///home/user/audio.mp3"));
+    assert!(!is_remote_http("-o /tmp/file"));
+    assert!(!is_remote_http("http://localhost:8080"));
+    assert!(!is_remote_http("http://10.0.0.1/admin"));
+    assert!(!is_remote_http("http://192.168.1.1"));
+    assert!(!is_remote_http("http://169.254.169.254/latest/meta-data"));
+    assert!(!is_remote_http("http://[::1]/"));
+}
+
+#[test]
+fn it_rejects_local_and_private_hosts() {
+    assert!(!is_remote_http("http://127.0.0.1/song.mp3"));
+    assert!(!is_remote_http("http://2130706433/"));
+    assert!(!is_remote_http("http://0.0.0.0:8080"));
+    assert!(!is_remote_http("http://172.16.0.1"));
+    assert!(!is_remote_http("http://100.64.0.1"));
+    assert!(!is_remote_http("http://sub.localhost/"));
+    assert!(!is_remote_http("http://LOCALHOST./"));
+    assert!(!is_remote_http("http://[::ffff:127.0.0.1]/"));
+    assert!(!is_remote_http("http://[::ffff:169.254.169.254]/"));
+    assert!(!is_remote_http("http://[fc00::1]/"));
+    assert!(!is_remote_http("http://[fe80::1]/"));
+    assert!(!is_remote_http("ftp://domain.com/song.mp3"));
+    assert!(is_remote_http("http://100.128.0.1"));
+    assert!(is_remote_http("http://[2606:4700::1111]/"));
+}
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+/// Parses a duration in the format `[[hh:]mm:]ss`
+/// Example
+/// ```
+/// use bot_coreutils::time::parse_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_duration("1:30"), Some(Duration::from_secs(90)));
+/// assert_eq!(parse_duration("45"), Some(Duration::from_secs(45)));
+/// assert_eq!(parse_duration("invalid"), None);
+/// ```
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    let parts: Vec<&str> = value.trim().split(':').collect();
+
+    if parts.len() > 3 {
+        return None;
+    }
+    let mut seconds = 0u64;
+
+    for (i, part) in parts.into_iter().enumerate() {
+        let value = part.parse::<u64>().ok()?;
+
+        // only the first component may exceed the range of minutes or seconds
+        if i > 0 && value >= 60 {
+            return None;
+        }
+        seconds = seconds.checked_mul(60)?.checked_add(value)?;
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Formats a duration as `mm:ss` or `h:mm:ss` if it's longer than an hour
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    let seconds = seconds % 60;
+
+    if hours > 0 {
+        format!("{}:{:0>2}:{:0>2}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:0>2}", minutes, seconds)
+    }
+}
@@ -70,7 +70,71 @@ pub fn is_video(url_str: &str) -> bool {
     }
 }
 
+/// Returns if a given url could be an audio file
+pub fn is_audio(url_str: &str) -> bool {
+    if let Some(guess) = guess_mime_for_url(url_str) {
+        guess.type_() == mime::AUDIO
+    } else {
+        false
+    }
+}
+
 /// Returns if the given url is valid
 pub fn is_valid(url_str: &str) -> bool {
     Url::parse(url_str).is_ok()
 }
+
+/// Returns if the given url uses http(s) and points to a host that isn't
+/// the local machine or a private network address
+/// Example
+/// ```
+/// use bot_coreutils::url::is_remote_http;
+///
+/// assert!(is_remote_http("https://example.com/song.mp3"));
+/// assert!(!is_remote_http("file:///etc/passwd"));
+/// assert!(!is_remote_http("http://127.0.0.1:8080"));
+/// ```
+pub fn is_remote_http(url_str: &str) -> bool {
+    let url = if let Ok(url) = Url::parse(url_str) {
+        url
+    } else {
+        return false;
+    };
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return false;
+    }
+
+    match url.host() {
+        Some(Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_lowercase();
+            domain != "localhost" && !domain.ends_with(".localhost")
+        }
+        Some(Host::Ipv4(ip)) => is_public_ipv4(ip),
+        Some(Host::Ipv6(ip)) => {
+            if let Some(ip) = ip.to_ipv4_mapped() {
+                return is_public_ipv4(ip);
+            }
+            let segment = ip.segments()[0];
+            !(ip.is_loopback()
+                || ip.is_unspecified()
+                || (segment & 0xfe00) == 0xfc00
+                || (segment & 0xffc0) == 0xfe80)
+        }
+        None => false,
+    }
+}
+
+/// Returns if the address isn't reserved for the local machine or private networks
+fn is_public_ipv4(ip: std::net::Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    // the shared address space 100.64.0.0/10 used for carrier-grade NAT
+    let shared = first == 100 && (second & 0xc0) == 64;
+
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || first == 0
+        || shared)
+}
@@ -2,6 +2,10 @@ pub use sea_orm_migration::prelude::*;
 
 mod m20220029_164527_change_timestamp_format;
 mod m20220101_000001_create_table;
+mod m20261018_000001_create_music_queues;
+mod m20261018_000002_create_lyrics;
+mod m20261018_000003_create_playlists;
+mod m20261018_000004_create_favorites;
 
 pub struct Migrator;
 
@@ -11,6 +15,10 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20220101_000001_create_table::Migration),
             Box::new(m20220029_164527_change_timestamp_format::Migration),
+            Box::new(m20261018_000001_create_music_queues::Migration),
+            Box::new(m20261018_000002_create_lyrics::Migration),
+            Box::new(m20261018_000003_create_playlists::Migration),
+            Box::new(m20261018_000004_create_favorites::Migration),
         ]
     }
 }
@@ -0,0 +1,75 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum MusicQueues {
+    Table,
+    GuildId,
+    VoiceChannelId,
+    MsgChannelId,
+    Paused,
+    Position,
+    CurrentSong,
+    Queue,
+}
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261018_000001_create_music_queues"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(music_queues()).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MusicQueues::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn music_queues() -> TableCreateStatement {
+    Table::create()
+        .table(MusicQueues::Table)
+        .if_not_exists()
+        .col(
+            ColumnDef::new(MusicQueues::GuildId)
+                .big_integer()
+                .not_null()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(MusicQueues::VoiceChannelId)
+                .big_integer()
+                .not_null(),
+        )
+        .col(
+            ColumnDef::new(MusicQueues::MsgChannelId)
+                .big_integer()
+                .not_null(),
+        )
+        .col(
+            ColumnDef::new(MusicQueues::Paused)
+                .boolean()
+                .not_null()
+                .default(false),
+        )
+        .col(
+            ColumnDef::new(MusicQueues::Position)
+                .big_integer()
+                .not_null()
+                .default(0),
+        )
+        .col(ColumnDef::new(MusicQueues::CurrentSong).text())
+        .col(ColumnDef::new(MusicQueues::Queue).text().not_null())
+        .to_owned()
+}
@@ -0,0 +1,55 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum Lyrics {
+    Table,
+    Id,
+    Artist,
+    Title,
+    Provider,
+    Plain,
+    Synced,
+}
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261018_000002_create_lyrics"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(lyrics()).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Lyrics::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn lyrics() -> TableCreateStatement {
+    Table::create()
+        .table(Lyrics::Table)
+        .if_not_exists()
+        .col(
+            ColumnDef::new(Lyrics::Id)
+                .big_integer()
+                .primary_key()
+                .auto_increment(),
+        )
+        .col(ColumnDef::new(Lyrics::Artist).string_len(255).not_null())
+        .col(ColumnDef::new(Lyrics::Title).string_len(255).not_null())
+        .col(ColumnDef::new(Lyrics::Provider).string_len(64).not_null())
+        .col(ColumnDef::new(Lyrics::Plain).text().not_null())
+        .col(ColumnDef::new(Lyrics::Synced).text())
+        .to_owned()
+}
@@ -0,0 +1,96 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum Playlists {
+    Table,
+    Id,
+    GuildId,
+    Name,
+}
+
+#[derive(Iden)]
+pub enum PlaylistSongs {
+    Table,
+    Id,
+    PlaylistId,
+    Position,
+    Song,
+}
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261018_000003_create_playlists"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(playlists()).await?;
+        manager.create_table(playlist_songs()).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PlaylistSongs::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Playlists::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn playlists() -> TableCreateStatement {
+    Table::create()
+        .table(Playlists::Table)
+        .if_not_exists()
+        .col(
+            ColumnDef::new(Playlists::Id)
+                .big_integer()
+                .primary_key()
+                .auto_increment(),
+        )
+        .col(ColumnDef::new(Playlists::GuildId).big_integer().not_null())
+        .col(ColumnDef::new(Playlists::Name).string_len(255).not_null())
+        .index(
+            Index::create()
+                .name("idx-playlists-guild-name")
+                .col(Playlists::GuildId)
+                .col(Playlists::Name)
+                .unique(),
+        )
+        .to_owned()
+}
+
+fn playlist_songs() -> TableCreateStatement {
+    Table::create()
+        .table(PlaylistSongs::Table)
+        .if_not_exists()
+        .col(
+            ColumnDef::new(PlaylistSongs::Id)
+                .big_integer()
+                .primary_key()
+                .auto_increment(),
+        )
+        .col(
+            ColumnDef::new(PlaylistSongs::PlaylistId)
+                .big_integer()
+                .not_null(),
+        )
+        .col(ColumnDef::new(PlaylistSongs::Position).integer().not_null())
+        .col(ColumnDef::new(PlaylistSongs::Song).text().not_null())
+        .foreign_key(
+            ForeignKey::create()
+                .name("fk-playlist-songs-playlist")
+                .from(PlaylistSongs::Table, PlaylistSongs::PlaylistId)
+                .to(Playlists::Table, Playlists::Id)
+                .on_delete(ForeignKeyAction::Cascade),
+        )
+        .to_owned()
+}
@@ -0,0 +1,135 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum Favorites {
+    Table,
+    Id,
+    UserId,
+    Song,
+}
+
+#[derive(Iden)]
+pub enum UserPlaylists {
+    Table,
+    Id,
+    UserId,
+    Name,
+}
+
+#[derive(Iden)]
+pub enum UserPlaylistSongs {
+    Table,
+    Id,
+    PlaylistId,
+    Position,
+    Song,
+}
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261018_000004_create_favorites"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(favorites()).await?;
+        manager.create_table(user_playlists()).await?;
+        manager.create_table(user_playlist_songs()).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserPlaylistSongs::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(UserPlaylists::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Favorites::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn favorites() -> TableCreateStatement {
+    Table::create()
+        .table(Favorites::Table)
+        .if_not_exists()
+        .col(
+            ColumnDef::new(Favorites::Id)
+                .big_integer()
+                .primary_key()
+                .auto_increment(),
+        )
+        .col(ColumnDef::new(Favorites::UserId).big_integer().not_null())
+        .col(ColumnDef::new(Favorites::Song).text().not_null())
+        .to_owned()
+}
+
+fn user_playlists() -> TableCreateStatement {
+    Table::create()
+        .table(UserPlaylists::Table)
+        .if_not_exists()
+        .col(
+            ColumnDef::new(UserPlaylists::Id)
+                .big_integer()
+                .primary_key()
+                .auto_increment(),
+        )
+        .col(
+            ColumnDef::new(UserPlaylists::UserId)
+                .big_integer()
+                .not_null(),
+        )
+        .col(
+            ColumnDef::new(UserPlaylists::Name)
+                .string_len(255)
+                .not_null(),
+        )
+        .index(
+            Index::create()
+                .name("idx-user-playlists-user-name")
+                .col(UserPlaylists::UserId)
+                .col(UserPlaylists::Name)
+                .unique(),
+        )
+        .to_owned()
+}
+
+fn user_playlist_songs() -> TableCreateStatement {
+    Table::create()
+        .table(UserPlaylistSongs::Table)
+        .if_not_exists()
+        .col(
+            ColumnDef::new(UserPlaylistSongs::Id)
+                .big_integer()
+                .primary_key()
+                .auto_increment(),
+        )
+        .col(
+            ColumnDef::new(UserPlaylistSongs::PlaylistId)
+                .big_integer()
+                .not_null(),
+        )
+        .col(
+            ColumnDef::new(UserPlaylistSongs::Position)
+                .integer()
+                .not_null(),
+        )
+        .col(ColumnDef::new(UserPlaylistSongs::Song).text().not_null())
+        .foreign_key(
+            ForeignKey::create()
+                .name("fk-user-playlist-songs-playlist")
+                .from(UserPlaylistSongs::Table, UserPlaylistSongs::PlaylistId)
+                .to(UserPlaylists::Table, UserPlaylists::Id)
+                .on_delete(ForeignKeyAction::Cascade),
+        )
+        .to_owned()
+}
@@ -0,0 +1,43 @@
+use crate::entity::favorites;
+use crate::error::DatabaseResult;
+use sea_orm::prelude::*;
+use sea_orm::ActiveValue::Set;
+use sea_orm::QueryOrder;
+
+impl super::BotDatabase {
+    /// Returns the favorite songs of a user in the order they were added
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_favorites(&self, user_id: u64) -> DatabaseResult<Vec<favorites::Model>> {
+        let favorites = favorites::Entity::find()
+            .filter(favorites::Column::UserId.eq(user_id as i64))
+            .order_by_asc(favorites::Column::Id)
+            .all(&self.db)
+            .await?;
+
+        Ok(favorites)
+    }
+
+    /// Adds a serialized song to the favorites of a user
+    #[tracing::instrument(level = "debug", skip(self, song))]
+    pub async fn add_favorite(&self, user_id: u64, song: String) -> DatabaseResult<()> {
+        let model = favorites::ActiveModel {
+            user_id: Set(user_id as i64),
+            song: Set(song),
+            ..Default::default()
+        };
+        model.insert(&self.db).await?;
+
+        Ok(())
+    }
+
+    /// Deletes a favorite song
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn delete_favorite(&self, id: i64) -> DatabaseResult<()> {
+        favorites::Entity::delete_many()
+            .filter(favorites::Column::Id.eq(id))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
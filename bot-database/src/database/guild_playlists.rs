@@ -44,11 +44,26 @@ impl super::BotDatabase {
     ) -> DatabaseResult<()> {
         let model = guild_playlists::ActiveModel {
             guild_id: Set(guild_id as i64),
-            name: Set(name),
+            name: Set(name.clone()),
             url: Set(url),
-            ..Default::default()
         };
-        model.insert(&self.db).await?;
+        if self.get_guild_playlist(guild_id, name).await?.is_some() {
+            model.update(&self.db).await?;
+        } else {
+            model.insert(&self.db).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Deletes a guild playlist by name
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn delete_guild_playlist(&self, guild_id: u64, name: String) -> DatabaseResult<()> {
+        guild_playlists::Entity::delete_many()
+            .filter(guild_playlists::Column::GuildId.eq(guild_id))
+            .filter(guild_playlists::Column::Name.eq(name))
+            .exec(&self.db)
+            .await?;
 
         Ok(())
     }
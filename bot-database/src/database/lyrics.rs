@@ -0,0 +1,53 @@
+use crate::entity::lyrics;
+use crate::error::DatabaseResult;
+use sea_orm::prelude::*;
+use sea_orm::ActiveValue::Set;
+
+impl super::BotDatabase {
+    /// Returns the cached lyrics for a song
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_lyrics(
+        &self,
+        artist: &str,
+        title: &str,
+    ) -> DatabaseResult<Option<lyrics::Model>> {
+        let lyrics = lyrics::Entity::find()
+            .filter(lyrics::Column::Artist.eq(artist))
+            .filter(lyrics::Column::Title.eq(title))
+            .one(&self.db)
+            .await?;
+
+        Ok(lyrics)
+    }
+
+    /// Caches the lyrics for a song replacing existing ones
+    #[tracing::instrument(level = "debug", skip(self, plain, synced))]
+    pub async fn set_lyrics(
+        &self,
+        artist: String,
+        title: String,
+        provider: String,
+        plain: String,
+        synced: Option<String>,
+    ) -> DatabaseResult<()> {
+        if let Some(model) = self.get_lyrics(&artist, &title).await? {
+            let mut active_model: lyrics::ActiveModel = model.into();
+            active_model.provider = Set(provider);
+            active_model.plain = Set(plain);
+            active_model.synced = Set(synced);
+            active_model.update(&self.db).await?;
+        } else {
+            let model = lyrics::ActiveModel {
+                artist: Set(artist),
+                title: Set(title),
+                provider: Set(provider),
+                plain: Set(plain),
+                synced: Set(synced),
+                ..Default::default()
+            };
+            model.insert(&self.db).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,16 +1,26 @@
 pub use ephemeral_messages::*;
+pub use favorites::*;
 pub use guild_playlists::*;
 pub use guild_playlists::*;
+pub use lyrics::*;
 pub use media::*;
+pub use music_queues::*;
+pub use playlists::*;
 use sea_orm::DatabaseConnection;
 pub use statistics::*;
+pub use user_playlists::*;
 pub use youtube_songs::*;
 
 mod ephemeral_messages;
+mod favorites;
 mod guild_playlists;
 mod guild_settings;
+mod lyrics;
 mod media;
+mod music_queues;
+mod playlists;
 mod statistics;
+mod user_playlists;
 mod youtube_songs;
 
 #[derive(Clone)]
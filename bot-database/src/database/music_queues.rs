@@ -0,0 +1,69 @@
+use crate::entity::music_queues;
+use crate::error::DatabaseResult;
+use sea_orm::prelude::*;
+use sea_orm::ActiveValue::Set;
+
+impl super::BotDatabase {
+    /// Returns all stored music queues
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_music_queues(&self) -> DatabaseResult<Vec<music_queues::Model>> {
+        let queues = music_queues::Entity::find().all(&self.db).await?;
+
+        Ok(queues)
+    }
+
+    /// Returns the stored music queue of a guild
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_music_queue(
+        &self,
+        guild_id: u64,
+    ) -> DatabaseResult<Option<music_queues::Model>> {
+        let queue = music_queues::Entity::find()
+            .filter(music_queues::Column::GuildId.eq(guild_id as i64))
+            .one(&self.db)
+            .await?;
+
+        Ok(queue)
+    }
+
+    /// Upserts the music queue of a guild
+    #[tracing::instrument(level = "debug", skip(self, queue))]
+    pub async fn set_music_queue(
+        &self,
+        guild_id: u64,
+        voice_channel_id: u64,
+        msg_channel_id: u64,
+        paused: bool,
+        position: i64,
+        current_song: Option<String>,
+        queue: String,
+    ) -> DatabaseResult<()> {
+        let model = music_queues::ActiveModel {
+            guild_id: Set(guild_id as i64),
+            voice_channel_id: Set(voice_channel_id as i64),
+            msg_channel_id: Set(msg_channel_id as i64),
+            paused: Set(paused),
+            position: Set(position),
+            current_song: Set(current_song),
+            queue: Set(queue),
+        };
+        if self.get_music_queue(guild_id).await?.is_some() {
+            model.update(&self.db).await?;
+        } else {
+            model.insert(&self.db).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Deletes the stored music queue of a guild
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn delete_music_queue(&self, guild_id: u64) -> DatabaseResult<()> {
+        music_queues::Entity::delete_many()
+            .filter(music_queues::Column::GuildId.eq(guild_id as i64))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use crate::entity::{playlist_songs, playlists};
+use crate::error::DatabaseResult;
+use sea_orm::prelude::*;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{PaginatorTrait, QueryOrder, TransactionTrait};
+
+impl super::BotDatabase {
+    /// Returns all playlists of a guild ordered by name
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_playlists(&self, guild_id: u64) -> DatabaseResult<Vec<playlists::Model>> {
+        let playlists = playlists::Entity::find()
+            .filter(playlists::Column::GuildId.eq(guild_id as i64))
+            .order_by_asc(playlists::Column::Name)
+            .all(&self.db)
+            .await?;
+
+        Ok(playlists)
+    }
+
+    /// Returns a playlist of a guild by name
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_playlist(
+        &self,
+        guild_id: u64,
+        name: &str,
+    ) -> DatabaseResult<Option<playlists::Model>> {
+        let playlist = playlists::Entity::find()
+            .filter(playlists::Column::GuildId.eq(guild_id as i64))
+            .filter(playlists::Column::Name.eq(name))
+            .one(&self.db)
+            .await?;
+
+        Ok(playlist)
+    }
+
+    /// Creates a new empty playlist
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn create_playlist(
+        &self,
+        guild_id: u64,
+        name: String,
+    ) -> DatabaseResult<playlists::Model> {
+        let model = playlists::ActiveModel {
+            guild_id: Set(guild_id as i64),
+            name: Set(name),
+            ..Default::default()
+        };
+
+        Ok(model.insert(&self.db).await?)
+    }
+
+    /// Renames a playlist
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn rename_playlist(&self, playlist_id: i64, name: String) -> DatabaseResult<()> {
+        let model = playlists::ActiveModel {
+            id: Set(playlist_id),
+            name: Set(name),
+            ..Default::default()
+        };
+        model.update(&self.db).await?;
+
+        Ok(())
+    }
+
+    /// Deletes a playlist with all of its songs
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn delete_playlist(&self, playlist_id: i64) -> DatabaseResult<()> {
+        let txn = self.db.begin().await?;
+        playlist_songs::Entity::delete_many()
+            .filter(playlist_songs::Column::PlaylistId.eq(playlist_id))
+            .exec(&txn)
+            .await?;
+        playlists::Entity::delete_many()
+            .filter(playlists::Column::Id.eq(playlist_id))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// Returns the songs of a playlist in their order
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_playlist_songs(
+        &self,
+        playlist_id: i64,
+    ) -> DatabaseResult<Vec<playlist_songs::Model>> {
+        let songs = playlist_songs::Entity::find()
+            .filter(playlist_songs::Column::PlaylistId.eq(playlist_id))
+            .order_by_asc(playlist_songs::Column::Position)
+            .all(&self.db)
+            .await?;
+
+        Ok(songs)
+    }
+
+    /// Returns the number of songs in a playlist
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_playlist_song_count(&self, playlist_id: i64) -> DatabaseResult<usize> {
+        let count = playlist_songs::Entity::find()
+            .filter(playlist_songs::Column::PlaylistId.eq(playlist_id))
+            .count(&self.db)
+            .await?;
+
+        Ok(count)
+    }
+
+    /// Replaces the songs of a playlist with the given serialized songs
+    #[tracing::instrument(level = "debug", skip(self, songs))]
+    pub async fn set_playlist_songs(
+        &self,
+        playlist_id: i64,
+        songs: Vec<String>,
+    ) -> DatabaseResult<()> {
+        let txn = self.db.begin().await?;
+        playlist_songs::Entity::delete_many()
+            .filter(playlist_songs::Column::PlaylistId.eq(playlist_id))
+            .exec(&txn)
+            .await?;
+
+        if !songs.is_empty() {
+            let models = songs
+                .into_iter()
+                .enumerate()
+                .map(|(i, song)| playlist_songs::ActiveModel {
+                    playlist_id: Set(playlist_id),
+                    position: Set(i as i32),
+                    song: Set(song),
+                    ..Default::default()
+                });
+            playlist_songs::Entity::insert_many(models)
+                .exec(&txn)
+                .await?;
+        }
+        txn.commit().await?;
+
+        Ok(())
+    }
+}
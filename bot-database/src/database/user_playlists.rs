@@ -0,0 +1,129 @@
+use crate::entity::{user_playlist_songs, user_playlists};
+use crate::error::DatabaseResult;
+use sea_orm::prelude::*;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{PaginatorTrait, QueryOrder, TransactionTrait};
+
+impl super::BotDatabase {
+    /// Returns all private playlists of a user ordered by name
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_user_playlists(
+        &self,
+        user_id: u64,
+    ) -> DatabaseResult<Vec<user_playlists::Model>> {
+        let playlists = user_playlists::Entity::find()
+            .filter(user_playlists::Column::UserId.eq(user_id as i64))
+            .order_by_asc(user_playlists::Column::Name)
+            .all(&self.db)
+            .await?;
+
+        Ok(playlists)
+    }
+
+    /// Returns a private playlist of a user by name
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_user_playlist(
+        &self,
+        user_id: u64,
+        name: &str,
+    ) -> DatabaseResult<Option<user_playlists::Model>> {
+        let playlist = user_playlists::Entity::find()
+            .filter(user_playlists::Column::UserId.eq(user_id as i64))
+            .filter(user_playlists::Column::Name.eq(name))
+            .one(&self.db)
+            .await?;
+
+        Ok(playlist)
+    }
+
+    /// Creates a new empty private playlist
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn create_user_playlist(
+        &self,
+        user_id: u64,
+        name: String,
+    ) -> DatabaseResult<user_playlists::Model> {
+        let model = user_playlists::ActiveModel {
+            user_id: Set(user_id as i64),
+            name: Set(name),
+            ..Default::default()
+        };
+
+        Ok(model.insert(&self.db).await?)
+    }
+
+    /// Deletes a private playlist with all of its songs
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn delete_user_playlist(&self, playlist_id: i64) -> DatabaseResult<()> {
+        let txn = self.db.begin().await?;
+        user_playlist_songs::Entity::delete_many()
+            .filter(user_playlist_songs::Column::PlaylistId.eq(playlist_id))
+            .exec(&txn)
+            .await?;
+        user_playlists::Entity::delete_many()
+            .filter(user_playlists::Column::Id.eq(playlist_id))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// Returns the songs of a private playlist in their order
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_user_playlist_songs(
+        &self,
+        playlist_id: i64,
+    ) -> DatabaseResult<Vec<user_playlist_songs::Model>> {
+        let songs = user_playlist_songs::Entity::find()
+            .filter(user_playlist_songs::Column::PlaylistId.eq(playlist_id))
+            .order_by_asc(user_playlist_songs::Column::Position)
+            .all(&self.db)
+            .await?;
+
+        Ok(songs)
+    }
+
+    /// Returns the number of songs in a private playlist
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_user_playlist_song_count(&self, playlist_id: i64) -> DatabaseResult<usize> {
+        let count = user_playlist_songs::Entity::find()
+            .filter(user_playlist_songs::Column::PlaylistId.eq(playlist_id))
+            .count(&self.db)
+            .await?;
+
+        Ok(count)
+    }
+
+    /// Replaces the songs of a private playlist with the given serialized songs
+    #[tracing::instrument(level = "debug", skip(self, songs))]
+    pub async fn set_user_playlist_songs(
+        &self,
+        playlist_id: i64,
+        songs: Vec<String>,
+    ) -> DatabaseResult<()> {
+        let txn = self.db.begin().await?;
+        user_playlist_songs::Entity::delete_many()
+            .filter(user_playlist_songs::Column::PlaylistId.eq(playlist_id))
+            .exec(&txn)
+            .await?;
+
+        if !songs.is_empty() {
+            let models = songs
+                .into_iter()
+                .enumerate()
+                .map(|(i, song)| user_playlist_songs::ActiveModel {
+                    playlist_id: Set(playlist_id),
+                    position: Set(i as i32),
+                    song: Set(song),
+                    ..Default::default()
+                });
+            user_playlist_songs::Entity::insert_many(models)
+                .exec(&txn)
+                .await?;
+        }
+        txn.commit().await?;
+
+        Ok(())
+    }
+}
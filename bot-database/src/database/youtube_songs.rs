@@ -2,6 +2,7 @@ use crate::entity::youtube_songs;
 use crate::error::DatabaseResult;
 use sea_orm::prelude::*;
 use sea_orm::ActiveValue::Set;
+use sea_orm::{Condition, QueryOrder, QuerySelect};
 
 impl super::BotDatabase {
     /// Adds a song to the database or increments the score when it
@@ -45,6 +46,28 @@ impl super::BotDatabase {
         Ok(song)
     }
 
+    /// Returns the songs with the best score that were made by one of the given artists
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn get_songs_by_artists(
+        &self,
+        artists: Vec<String>,
+        limit: u64,
+    ) -> DatabaseResult<Vec<youtube_songs::Model>> {
+        let condition = artists
+            .iter()
+            .fold(Condition::any(), |condition, artist| {
+                condition.add(youtube_songs::Column::Artist.contains(artist))
+            });
+        let songs = youtube_songs::Entity::find()
+            .filter(condition)
+            .order_by_desc(youtube_songs::Column::Score)
+            .limit(limit)
+            .all(&self.db)
+            .await?;
+
+        Ok(songs)
+    }
+
     /// Deletes a song from the database
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn delete_song(&self, id: i64) -> DatabaseResult<()> {
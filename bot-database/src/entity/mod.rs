@@ -3,8 +3,15 @@
 pub mod prelude;
 
 pub mod ephemeral_messages;
+pub mod favorites;
 pub mod guild_playlists;
 pub mod guild_settings;
+pub mod lyrics;
 pub mod media;
+pub mod music_queues;
+pub mod playlist_songs;
+pub mod playlists;
 pub mod statistics;
+pub mod user_playlist_songs;
+pub mod user_playlists;
 pub mod youtube_songs;
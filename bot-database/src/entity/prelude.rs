@@ -1,8 +1,15 @@
 //! SeaORM Entity. Generated by sea-orm-codegen 0.7.0
 
 pub use super::ephemeral_messages::Entity as EphemeralMessages;
+pub use super::favorites::Entity as Favorites;
 pub use super::guild_playlists::Entity as GuildPlaylists;
 pub use super::guild_settings::Entity as GuildSettings;
+pub use super::lyrics::Entity as Lyrics;
 pub use super::media::Entity as Media;
+pub use super::music_queues::Entity as MusicQueues;
+pub use super::playlist_songs::Entity as PlaylistSongs;
+pub use super::playlists::Entity as Playlists;
 pub use super::statistics::Entity as Statistics;
+pub use super::user_playlist_songs::Entity as UserPlaylistSongs;
+pub use super::user_playlists::Entity as UserPlaylists;
 pub use super::youtube_songs::Entity as YoutubeSongs;
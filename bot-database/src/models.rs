@@ -1,8 +1,14 @@
 use super::entity;
 
 pub use entity::ephemeral_messages::Model as EphemeralMessage;
+pub use entity::favorites::Model as Favorite;
 pub use entity::guild_playlists::Model as GuildPlaylist;
 pub use entity::guild_settings::Model as GuildSetting;
 pub use entity::media::Model as Media;
+pub use entity::music_queues::Model as MusicQueue;
+pub use entity::playlist_songs::Model as PlaylistSong;
+pub use entity::playlists::Model as Playlist;
 pub use entity::statistics::Model as Statistic;
+pub use entity::user_playlist_songs::Model as UserPlaylistSong;
+pub use entity::user_playlists::Model as UserPlaylist;
 pub use entity::youtube_songs::Model as YoutubeSong;
@@ -30,6 +30,7 @@ async fn clear_queue(ctx: &Context, msg: &Message) -> CommandResult {
     {
         let mut player = player.lock().await;
         player.queue().clear();
+        player.on_queue_changed().await?;
     }
 
     EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| {
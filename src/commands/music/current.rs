@@ -10,7 +10,11 @@ use crate::messages::music::now_playing::create_now_playing_msg;
 
 #[command]
 #[only_in(guilds)]
-#[description("Displays the currently playing song")]
+#[description(
+    "Displays the currently playing song. \
+    Everyone can vote to skip and add the song to their favorites with the buttons, \
+    the other controls require the dj role"
+)]
 #[usage("")]
 #[aliases("nowplaying", "np")]
 #[bucket("general")]
@@ -0,0 +1,64 @@
+use std::borrow::Cow;
+
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandError, CommandResult};
+use serenity::model::channel::{AttachmentType, Message};
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::get_music_player_for_guild;
+use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use crate::providers::music::queue::Song;
+use crate::providers::music::queue_export::{export_songs, ExportFormat};
+
+#[command("export")]
+#[only_in(guilds)]
+#[description("Exports the current song and the queue as a json, m3u or xspf file")]
+#[usage("(json|m3u|xspf)")]
+#[example("m3u")]
+#[max_args(1)]
+#[bucket("general")]
+async fn export_queue(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    let format = if args.is_empty() {
+        ExportFormat::Json
+    } else {
+        args.single::<ExportFormat>()?
+    };
+    tracing::debug!("Exporting queue for guild {} as {}", guild.id, format);
+
+    let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
+        player
+    } else {
+        return create_no_voicechannel_message(&ctx.http, msg.channel_id)
+            .await
+            .map_err(CommandError::from);
+    };
+    let songs: Vec<Song> = {
+        let mut player = player.lock().await;
+        let queue = player.queue();
+        queue
+            .current()
+            .iter()
+            .chain(queue.entries().iter())
+            .cloned()
+            .collect()
+    };
+    if songs.is_empty() {
+        msg.channel_id.say(ctx, "The queue is empty").await?;
+        return Ok(());
+    }
+    let content = export_songs(&songs, format)?;
+    let attachment = AttachmentType::Bytes {
+        data: Cow::from(content.into_bytes()),
+        filename: format!("queue.{}", format.extension()),
+    };
+    msg.channel_id
+        .send_files(&ctx.http, vec![attachment], |m| {
+            m.content(format!("📤 Exported {} songs", songs.len()))
+        })
+        .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
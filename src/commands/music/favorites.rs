@@ -0,0 +1,32 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::CommandResult;
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::favorites_remove::FAVORITES_REMOVE_COMMAND;
+use crate::messages::music::queue::create_favorites_menu;
+use crate::providers::music::playlists::get_favorites;
+use crate::utils::context_data::get_database_from_context;
+
+#[command]
+#[description("Shows your favorite songs. Use `play fav:` to play them")]
+#[usage("(remove <index>)")]
+#[aliases("favourites", "favs")]
+#[bucket("general")]
+#[sub_commands(favorites_remove)]
+async fn favorites(ctx: &Context, msg: &Message) -> CommandResult {
+    tracing::debug!("Displaying favorites of user {}", msg.author.id);
+    let database = get_database_from_context(ctx).await;
+    let songs = get_favorites(&database, msg.author.id)
+        .await?
+        .into_iter()
+        .enumerate()
+        .map(|(i, (_, s))| (i, s))
+        .collect();
+
+    create_favorites_menu(ctx, msg.channel_id, songs).await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
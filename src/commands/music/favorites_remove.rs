@@ -0,0 +1,38 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::providers::music::playlists::get_favorites;
+use crate::utils::context_data::get_database_from_context;
+
+#[command("remove")]
+#[description("Removes a song from your favorites")]
+#[usage("<index>")]
+#[example("2")]
+#[num_args(1)]
+#[bucket("general")]
+async fn favorites_remove(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index: usize = args.single()?;
+    tracing::debug!("Removing favorite {} of user {}", index, msg.author.id);
+    let database = get_database_from_context(ctx).await;
+    let favorites = get_favorites(&database, msg.author.id).await?;
+
+    if let Some((id, song)) = favorites.get(index) {
+        database.delete_favorite(*id).await?;
+        msg.channel_id
+            .say(
+                ctx,
+                format!("Removed **{}** from your favorites", song.title()),
+            )
+            .await?;
+    } else {
+        msg.channel_id
+            .say(ctx, format!("You have no favorite at position {}", index))
+            .await?;
+    }
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
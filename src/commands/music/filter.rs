@@ -0,0 +1,75 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandError, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::{get_music_player_for_guild, DJ_CHECK};
+use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use crate::providers::music::filters::AudioFilter;
+use serenity_additions::core::SHORT_TIMEOUT;
+use serenity_additions::ephemeral_message::EphemeralMessage;
+
+#[command]
+#[only_in(guilds)]
+#[description(
+    "Applies audio filters to the playback. Available filters are bassboost, nightcore, \
+     vaporwave, 8d, loudnorm and speed=<0.5-2>. Use `off` to remove all filters"
+)]
+#[usage("(off|<filter>...)")]
+#[example("bassboost speed=1.25")]
+#[min_args(1)]
+#[aliases("filters", "fx")]
+#[bucket("general")]
+#[checks(DJ)]
+async fn filter(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    tracing::debug!("Changing filters for guild {}", guild.id);
+
+    let mut filters = Vec::new();
+
+    for arg in args.iter::<String>() {
+        let arg = arg?;
+
+        if arg.eq_ignore_ascii_case("off") || arg.eq_ignore_ascii_case("none") {
+            filters.clear();
+            break;
+        }
+        let filter = crate::forward_error!(ctx, msg.channel_id, arg.parse::<AudioFilter>());
+
+        if !filters.contains(&filter) {
+            filters.push(filter);
+        }
+    }
+    let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
+        player
+    } else {
+        return create_no_voicechannel_message(&ctx.http, msg.channel_id)
+            .await
+            .map_err(CommandError::from);
+    };
+    {
+        let mut player = player.lock().await;
+        player.set_filters(filters.clone()).await?;
+        player.update_now_playing().await?;
+    }
+
+    EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| {
+        if filters.is_empty() {
+            m.content("🎛️ Removed all filters")
+        } else {
+            m.content(format!(
+                "🎛️ Applied the filters `{}`",
+                filters
+                    .iter()
+                    .map(|f| f.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ))
+        }
+    })
+    .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandError, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::{get_music_player_for_guild, DJ_CHECK};
+use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use bot_coreutils::time::format_duration;
+use serenity_additions::core::SHORT_TIMEOUT;
+use serenity_additions::ephemeral_message::EphemeralMessage;
+
+#[command]
+#[only_in(guilds)]
+#[description("Skips forward in the current song by the given number of seconds")]
+#[usage("[<seconds>]")]
+#[example("30")]
+#[max_args(1)]
+#[aliases("ff", "fwd")]
+#[bucket("general")]
+#[checks(DJ)]
+async fn forward(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    tracing::debug!("Seeking in song for guild {}", guild.id);
+
+    let seconds = if args.is_empty() {
+        10
+    } else {
+        crate::forward_error!(
+            ctx,
+            msg.channel_id,
+            args.single::<u64>()
+                .map_err(|_| "Invalid number of seconds")
+        )
+    };
+    let amount = Duration::from_secs(seconds);
+    let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
+        player
+    } else {
+        return create_no_voicechannel_message(&ctx.http, msg.channel_id)
+            .await
+            .map_err(CommandError::from);
+    };
+    let position = {
+        let mut player = player.lock().await;
+        let position = player.forward(amount).await?;
+        player.update_now_playing().await?;
+        position
+    };
+
+    EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| {
+        m.content(format!("⏩ Skipped forward to `{}`", format_duration(position)))
+    })
+    .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{CommandError, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::get_music_player_for_guild;
+use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use crate::messages::music::queue::create_history_menu;
+use crate::providers::music::queue::Song;
+
+#[command]
+#[only_in(guilds)]
+#[description("Shows the recently played songs")]
+#[usage("")]
+#[aliases("recent")]
+#[bucket("general")]
+async fn history(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    tracing::trace!("Displaying history for guild {}", guild.id);
+
+    let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
+        player
+    } else {
+        return create_no_voicechannel_message(&ctx.http, msg.channel_id)
+            .await
+            .map_err(CommandError::from);
+    };
+    let songs: Vec<(usize, Song)> = {
+        let mut player = player.lock().await;
+        player
+            .queue()
+            .history()
+            .iter()
+            .rev()
+            .cloned()
+            .enumerate()
+            .map(|(i, s)| (i + 1, s))
+            .collect()
+    };
+    tracing::trace!("Songs are {:?}", songs);
+
+    if songs.is_empty() {
+        msg.channel_id
+            .send_message(ctx, |m| {
+                m.embed(|e| e.title("History").description("*No songs have been played yet*"))
+            })
+            .await?;
+
+        return Ok(());
+    }
+    create_history_menu(ctx, msg.channel_id, songs).await?;
+
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
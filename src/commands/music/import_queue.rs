@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::CommandResult;
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::{
+    enqueue_songs, get_channel_for_author, get_music_player_for_guild, queue_capacity,
+};
+use crate::providers::music::player::MusicPlayer;
+use crate::providers::music::queue::Song;
+use crate::providers::music::queue_export::{
+    import_songs, ExportFormat, ExportedSong, ExportedSource,
+};
+use crate::utils::context_data::Store;
+use crate::utils::error::BotResult;
+
+/// The maximum size of an exported queue file in bytes
+static MAX_IMPORT_SIZE: u64 = 1024 * 1024;
+
+/// The maximum number of songs imported at once
+static MAX_IMPORT_SONGS: usize = 500;
+
+#[command("import")]
+#[only_in(guilds)]
+#[description(
+    "Adds the songs of an exported queue attached to the message or the message replied to"
+)]
+#[usage("")]
+#[bucket("music_api")]
+async fn import_queue(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    tracing::debug!("Importing queue for guild {}", guild.id);
+
+    let attachment = msg.attachments.first().cloned().or_else(|| {
+        msg.referenced_message
+            .as_ref()
+            .and_then(|m| m.attachments.first().cloned())
+    });
+    let attachment = if let Some(attachment) = attachment {
+        attachment
+    } else {
+        msg.channel_id
+            .say(
+                ctx,
+                "No exported queue found. Attach a file or reply to one.",
+            )
+            .await?;
+        return Ok(());
+    };
+    let format = ExportFormat::from_filename(&attachment.filename)
+        .ok_or("Unsupported file type. Use json, m3u or xspf.")?;
+
+    if attachment.size > MAX_IMPORT_SIZE {
+        msg.channel_id
+            .say(ctx, "The exported queue is too large to be imported")
+            .await?;
+        return Ok(());
+    }
+    let content = attachment.download().await?;
+    let mut exported = import_songs(&String::from_utf8_lossy(&content), format)?;
+    tracing::debug!("Attachment contains {} songs", exported.len());
+
+    let mut player = get_music_player_for_guild(ctx, guild.id).await;
+
+    if player.is_none() {
+        tracing::debug!("Not in a channel. Joining authors channel...");
+        let channel_id = get_channel_for_author(&msg.author.id, &guild)?;
+        let music_player = MusicPlayer::join(ctx, guild.id, channel_id, msg.channel_id).await?;
+        player = Some(music_player);
+    }
+    let player = player.unwrap();
+    let capacity = queue_capacity(ctx, msg, &player, exported.len().min(MAX_IMPORT_SONGS)).await?;
+
+    if capacity == 0 {
+        msg.channel_id
+            .say(ctx, "The queue limits don't allow importing these songs")
+            .await?;
+        return Ok(());
+    }
+    if capacity < exported.len() {
+        msg.channel_id
+            .say(
+                ctx,
+                format!(
+                    "⚠️ Only the first {} of {} songs are imported",
+                    capacity,
+                    exported.len()
+                ),
+            )
+            .await?;
+        exported.truncate(capacity);
+    }
+    let total = exported.len();
+    let songs = get_songs_for_exported(ctx, exported).await?;
+
+    if songs.len() < total {
+        msg.channel_id
+            .say(
+                ctx,
+                format!("⚠️ {} songs could not be imported", total - songs.len()),
+            )
+            .await?;
+    }
+    enqueue_songs(ctx, msg, player, songs).await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
+
+/// Rebuilds the songs of an exported queue.
+/// Spotify tracks are looked up again so that the stored youtube video can be reused for them
+async fn get_songs_for_exported(
+    ctx: &Context,
+    exported: Vec<ExportedSong>,
+) -> BotResult<Vec<Song>> {
+    let spotify = {
+        let data = ctx.data.read().await;
+        Arc::clone(&data.get::<Store>().unwrap().spotify_api)
+    };
+    let mut songs = Vec::new();
+
+    for entry in exported {
+        if let ExportedSource::Spotify { id: Some(id) } = &entry.source {
+            let url = format!("https://open.spotify.com/track/{}", id);
+
+            match spotify.get_track_for_url(&url).await {
+                Ok(track) => {
+                    songs.push(track.into());
+                    continue;
+                }
+                Err(e) => tracing::error!("Failed to fetch spotify track {}: {:?}", id, e),
+            }
+        }
+        if let ExportedSource::SpotifyEpisode { id } = &entry.source {
+            let url = format!("https://open.spotify.com/episode/{}", id);
+
+            match spotify.get_episode_for_url(&url).await {
+                Ok(episode) => songs.push(episode.into()),
+                Err(e) => tracing::error!("Failed to fetch spotify episode {}: {:?}", id, e),
+            }
+            continue;
+        }
+        if let Some(song) = entry.into_song() {
+            songs.push(song);
+        }
+    }
+
+    Ok(songs)
+}
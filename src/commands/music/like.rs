@@ -0,0 +1,50 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{CommandError, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::get_music_player_for_guild;
+use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use crate::providers::music::playlists::add_favorite;
+use crate::utils::context_data::get_database_from_context;
+
+#[command]
+#[only_in(guilds)]
+#[description("Adds the currently playing song to your favorites")]
+#[usage("")]
+#[aliases("fav", "heart")]
+#[bucket("general")]
+async fn like(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    tracing::debug!("Liking song playing in {}", guild.id);
+
+    let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
+        player
+    } else {
+        return create_no_voicechannel_message(&ctx.http, msg.channel_id)
+            .await
+            .map_err(CommandError::from);
+    };
+    let current = player.lock().await.queue().current().clone();
+
+    if let Some(song) = current {
+        let database = get_database_from_context(ctx).await;
+        let title = song.title().clone();
+
+        if add_favorite(&database, msg.author.id, song).await? {
+            msg.channel_id
+                .say(ctx, format!("❤️ Added **{}** to your favorites", title))
+                .await?;
+        } else {
+            msg.channel_id
+                .say(ctx, format!("**{}** already is one of your favorites", title))
+                .await?;
+        }
+    } else {
+        msg.channel_id.say(ctx, "Nothing is playing").await?;
+    }
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
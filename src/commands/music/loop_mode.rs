@@ -0,0 +1,57 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandError, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::{get_music_player_for_guild, DJ_CHECK};
+use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use crate::providers::music::queue::LoopMode;
+use serenity_additions::core::SHORT_TIMEOUT;
+use serenity_additions::ephemeral_message::EphemeralMessage;
+
+#[command]
+#[only_in(guilds)]
+#[description("Sets the loop mode. Cycles through the modes if no mode is given")]
+#[usage("[(off|track|queue)]")]
+#[example("queue")]
+#[max_args(1)]
+#[aliases("loop", "repeat", "loop-mode", "loopmode")]
+#[bucket("general")]
+#[checks(DJ)]
+async fn loop_mode(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    tracing::debug!("Changing loop mode for guild {}", guild.id);
+
+    let mode = if args.is_empty() {
+        None
+    } else {
+        Some(crate::forward_error!(
+            ctx,
+            msg.channel_id,
+            args.single::<LoopMode>()
+        ))
+    };
+    let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
+        player
+    } else {
+        return create_no_voicechannel_message(&ctx.http, msg.channel_id)
+            .await
+            .map_err(CommandError::from);
+    };
+    let mode = {
+        let mut player = player.lock().await;
+        let mode = mode.unwrap_or_else(|| player.queue().loop_mode().cycle());
+        player.queue().set_loop_mode(mode);
+        player.update_now_playing().await?;
+        mode
+    };
+
+    EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| {
+        m.content(format!("{} Loop mode set to `{}`", mode.emoji(), mode))
+    })
+    .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
@@ -5,13 +5,18 @@ use serenity::model::channel::Message;
 
 use crate::commands::common::handle_autodelete;
 use crate::commands::music::get_music_player_for_guild;
+use crate::commands::music::lyrics_live::LYRICS_LIVE_COMMAND;
+use crate::messages::music::lyrics::create_lyrics_menu;
 use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use crate::providers::music::lyrics::{get_lyrics, LyricsQuery};
+use crate::utils::context_data::get_database_from_context;
 
 #[command]
 #[only_in(guilds)]
 #[description("Shows the lyrics for the currently playing song")]
-#[usage("")]
+#[usage("(live)")]
 #[bucket("general")]
+#[sub_commands(lyrics_live)]
 async fn lyrics(ctx: &Context, msg: &Message) -> CommandResult {
     let guild = msg.guild(&ctx.cache).unwrap();
     tracing::debug!("Fetching lyrics for song playing in {}", guild.id);
@@ -24,27 +29,16 @@ async fn lyrics(ctx: &Context, msg: &Message) -> CommandResult {
             .map_err(CommandError::from);
     };
 
-    let (lyrics, current) = {
-        let mut player = player.lock().await;
-        let current = player.queue().current().clone();
-        (player.lyrics().await?, current)
+    let current = player.lock().await.queue().current().clone();
+    let lyrics = if let Some(current) = &current {
+        let database = get_database_from_context(ctx).await;
+        get_lyrics(&database, &LyricsQuery::for_song(current)).await?
+    } else {
+        None
     };
 
-    if let Some(lyrics) = lyrics {
-        let current = current.unwrap();
-        msg.channel_id
-            .send_message(ctx, |m| {
-                m.embed(|e| {
-                    e.title(format!(
-                        "Lyrics for {} by {}",
-                        current.title(),
-                        current.author()
-                    ))
-                    .description(lyrics)
-                    .footer(|f| f.text("Powered by lyricsovh"))
-                })
-            })
-            .await?;
+    if let (Some(lyrics), Some(current)) = (lyrics, current) {
+        create_lyrics_menu(ctx, msg.channel_id, &current, lyrics).await?;
     } else {
         tracing::debug!("No lyrics found");
         msg.channel_id.say(ctx, "No lyrics found").await?;
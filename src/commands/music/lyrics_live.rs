@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{CommandError, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::get_music_player_for_guild;
+use crate::messages::music::lyrics::create_live_lyrics_msg;
+use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use crate::providers::music::lyrics::{get_lyrics, LyricsQuery};
+use crate::utils::context_data::get_database_from_context;
+
+#[command("live")]
+#[only_in(guilds)]
+#[description("Shows the time-synced lyrics of the currently playing song line by line")]
+#[usage("")]
+#[bucket("general")]
+async fn lyrics_live(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    tracing::debug!("Showing live lyrics for song playing in {}", guild.id);
+
+    let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
+        player
+    } else {
+        return create_no_voicechannel_message(&ctx.http, msg.channel_id)
+            .await
+            .map_err(CommandError::from);
+    };
+
+    let current = player.lock().await.queue().current().clone();
+    let lyrics = if let Some(current) = &current {
+        let database = get_database_from_context(ctx).await;
+        get_lyrics(&database, &LyricsQuery::for_song(current)).await?
+    } else {
+        None
+    };
+    let (lyrics, current) = match (lyrics, current) {
+        (Some(lyrics), Some(current)) => (lyrics, current),
+        _ => {
+            tracing::debug!("No lyrics found");
+            msg.channel_id.say(ctx, "No lyrics found").await?;
+            return Ok(());
+        }
+    };
+
+    if let Some(lines) = lyrics.synced_lines() {
+        create_live_lyrics_msg(
+            ctx,
+            guild.id,
+            msg.channel_id,
+            Arc::clone(&player),
+            current,
+            lines,
+            lyrics.provider,
+        )
+        .await?;
+    } else {
+        msg.channel_id
+            .say(ctx, "No synced lyrics found for this song. Use `lyrics` instead.")
+            .await?;
+    }
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
@@ -1,9 +1,9 @@
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use aspotify::Track;
-use bot_database::Database;
-use futures::future::BoxFuture;
-use futures::FutureExt;
+use bot_coreutils::url;
+use futures::StreamExt;
 use regex::Regex;
 use serenity::client::Context;
 use serenity::framework::standard::macros::{check, group};
@@ -18,22 +18,49 @@ use youtube_metadata::get_video_information;
 
 use clear_queue::CLEAR_QUEUE_COMMAND;
 use current::CURRENT_COMMAND;
+use dedupe::DEDUPE_COMMAND;
+use favorites::FAVORITES_COMMAND;
+use filter::FILTER_COMMAND;
+use forward::FORWARD_COMMAND;
+use history::HISTORY_COMMAND;
 use join::JOIN_COMMAND;
 use leave::LEAVE_COMMAND;
+use like::LIKE_COMMAND;
+use loop_mode::LOOP_MODE_COMMAND;
 use lyrics::LYRICS_COMMAND;
 use move_song::MOVE_SONG_COMMAND;
+use my_playlist::MY_PLAYLIST_COMMAND;
 use pause::PAUSE_COMMAND;
 use play::PLAY_COMMAND;
 use play_next::PLAY_NEXT_COMMAND;
+use playlist::PLAYLIST_COMMAND;
 use playlists::PLAYLISTS_COMMAND;
+use previous::PREVIOUS_COMMAND;
 use queue::QUEUE_COMMAND;
 use remove_song::REMOVE_SONG_COMMAND;
+use restart::RESTART_COMMAND;
+use rewind::REWIND_COMMAND;
 use save_playlist::SAVE_PLAYLIST_COMMAND;
+use search::SEARCH_COMMAND;
+use seek::SEEK_COMMAND;
 use shuffle::SHUFFLE_COMMAND;
 use skip::SKIP_COMMAND;
+use skip_to::SKIP_TO_COMMAND;
+use undo::UNDO_COMMAND;
+use volume::VOLUME_COMMAND;
 
+use crate::handler::get_own_channel_member_count;
+use crate::messages::music::now_playing::create_now_playing_msg;
+use crate::messages::music::search::{create_search_menu, SEARCH_RESULT_COUNT};
+use crate::providers::music::ffprobe::get_direct_song;
+use crate::providers::music::limits::{rejection_summary, QueueLimits};
 use crate::providers::music::player::MusicPlayer;
-use crate::providers::music::queue::Song;
+use crate::providers::music::playlists::{
+    get_favorites, get_playlist_songs, get_user_playlist, get_user_playlist_songs,
+};
+use crate::providers::music::queue::{ShuffleMode, Song};
+use crate::providers::music::resolver::SongResolver;
+use crate::providers::music::spotify::SpotifyItem;
 use crate::providers::music::{add_youtube_song_to_database, youtube_dl};
 use crate::providers::settings::{get_setting, Setting};
 use crate::utils::context_data::{DatabaseContainer, MusicPlayers, Store};
@@ -41,19 +68,50 @@ use crate::utils::error::{BotError, BotResult};
 
 mod clear_queue;
 mod current;
+mod dedupe;
+mod export_queue;
+mod favorites;
+mod favorites_remove;
+mod filter;
+mod forward;
+mod history;
+mod import_queue;
 mod join;
 mod leave;
+mod like;
+mod loop_mode;
 mod lyrics;
+mod lyrics_live;
 mod move_song;
+mod my_playlist;
+mod my_playlist_add;
+mod my_playlist_delete;
+mod my_playlist_remove;
+mod my_playlist_save_queue;
 mod pause;
 mod play;
 mod play_next;
+mod playlist;
+mod playlist_add;
+mod playlist_delete;
+mod playlist_move;
+mod playlist_remove;
+mod playlist_rename;
+mod playlist_save_queue;
 mod playlists;
+mod previous;
 mod queue;
 mod remove_song;
+mod restart;
+mod rewind;
 mod save_playlist;
+mod search;
+mod seek;
 mod shuffle;
 mod skip;
+mod skip_to;
+mod undo;
+mod volume;
 
 #[group]
 #[commands(
@@ -68,10 +126,27 @@ mod skip;
     clear_queue,
     pause,
     save_playlist,
+    playlist,
     playlists,
     lyrics,
     move_song,
-    remove_song
+    remove_song,
+    loop_mode,
+    previous,
+    history,
+    seek,
+    forward,
+    rewind,
+    restart,
+    volume,
+    filter,
+    search,
+    like,
+    favorites,
+    my_playlist,
+    skip_to,
+    dedupe,
+    undo
 )]
 pub struct Music;
 
@@ -103,28 +178,120 @@ pub async fn get_music_player_for_guild(
     players.get(&guild_id.0).cloned()
 }
 
+/// Searches for songs matching the query and shows a menu to pick one of them
+pub async fn search_songs(ctx: &Context, msg: &Message, query: &str) -> BotResult<()> {
+    let songs: Vec<Song> = youtube_dl::search_videos(query, SEARCH_RESULT_COUNT)
+        .await?
+        .into_iter()
+        .map(Song::from)
+        .collect();
+
+    if songs.is_empty() {
+        return Err(BotError::Msg(format!("Nothing found for {}", query)));
+    }
+    create_search_menu(ctx, msg, songs).await
+}
+
+/// Returns the music player for the guild of the message
+/// joining the voice channel of the author if there's none
+pub async fn get_or_join_music_player(
+    ctx: &Context,
+    msg: &Message,
+) -> BotResult<Arc<Mutex<MusicPlayer>>> {
+    let guild_id = msg.guild_id.ok_or(BotError::from("Not in a guild"))?;
+
+    if let Some(player) = get_music_player_for_guild(ctx, guild_id).await {
+        return Ok(player);
+    }
+    tracing::debug!("Not in a channel. Joining authors channel...");
+    let guild = ctx
+        .cache
+        .guild(guild_id)
+        .ok_or(BotError::from("Guild not found"))?;
+    let channel_id = get_channel_for_author(&msg.author.id, &guild)?;
+
+    MusicPlayer::join(ctx, guild_id, channel_id, msg.channel_id).await
+}
+
+/// The maximum number of episodes queued for a podcast show
+static SHOW_EPISODE_LIMIT: usize = 50;
+
+/// The maximum number of albums fetched for the discography of an artist
+static DISCOGRAPHY_ALBUM_LIMIT: usize = 20;
+
+/// The maximum number of tracks queued for the discography of an artist
+static DISCOGRAPHY_TRACK_LIMIT: usize = 200;
+
+/// The minimum time between edits of the message showing the progress of adding songs
+static PROGRESS_UPDATE_INTERVAL: Duration = Duration::from_secs(2);
+
 /// Returns the list of songs for a given url
 async fn get_songs_for_query(ctx: &Context, msg: &Message, query: &str) -> BotResult<Vec<Song>> {
     let guild_id = msg.guild_id.unwrap();
+    let (discography, query) = match query.strip_prefix("--discography ") {
+        Some(query) => (true, query.trim()),
+        None => (false, query),
+    };
     let mut query = query.to_string();
     lazy_static::lazy_static! {
         // expressions to determine the type of url
         static ref PLAYLIST_NAME_REGEX: Regex = Regex::new(r"^pl:(\S+)$").unwrap();
+        static ref USER_PLAYLIST_NAME_REGEX: Regex = Regex::new(r"^my:(\S+)$").unwrap();
         static ref YOUTUBE_URL_REGEX: Regex = Regex::new(r"^(https?(://))?(www\.)?(youtube\.com/watch\?.*v=.*)|(/youtu.be/.*)|(youtube\.com/playlist\?.*list=.*)$").unwrap();
         static ref SPOTIFY_PLAYLIST_REGEX: Regex = Regex::new(r"^(https?(://))?(www\.|open\.)?spotify\.com/playlist/.*").unwrap();
         static ref SPOTIFY_ALBUM_REGEX: Regex = Regex::new(r"^(https?(://))?(www\.|open\.)?spotify\.com/album/.*").unwrap();
         static ref SPOTIFY_SONG_REGEX: Regex = Regex::new(r"^(https?(://))?(www\.|open\.)?spotify\.com/track/.*").unwrap();
+        static ref SPOTIFY_ARTIST_REGEX: Regex = Regex::new(r"^(https?(://))?(www\.|open\.)?spotify\.com/artist/.*").unwrap();
+        static ref SPOTIFY_SHOW_REGEX: Regex = Regex::new(r"^(https?(://))?(www\.|open\.)?spotify\.com/show/.*").unwrap();
+        static ref SPOTIFY_EPISODE_REGEX: Regex = Regex::new(r"^(https?(://))?(www\.|open\.)?spotify\.com/episode/.*").unwrap();
+        static ref HTTP_URL_REGEX: Regex = Regex::new(r"^https?://\S+$").unwrap();
     }
     let mut songs = Vec::new();
+
+    if query.trim().is_empty() {
+        tracing::debug!("Query is empty. Using audio attachments");
+        for attachment in &msg.attachments {
+            if url::is_audio(&attachment.url) {
+                songs.push(get_direct_song(&attachment.url, msg.author.name.clone()).await);
+            }
+        }
+        if songs.is_empty() {
+            return Err(BotError::from("Provide a query or attach an audio file"));
+        }
+        return Ok(songs);
+    }
     let data = ctx.data.read().await;
     let store = data.get::<Store>().unwrap();
     let database = data.get::<DatabaseContainer>().unwrap();
 
     tracing::debug!("Querying play input {}", query);
+    if query.trim() == "fav:" {
+        tracing::debug!("Query is the favorites of the author");
+        songs = get_favorites(database, msg.author.id)
+            .await?
+            .into_iter()
+            .map(|(_, song)| song)
+            .collect();
+
+        if songs.is_empty() {
+            return Err(BotError::from("You have no favorites yet"));
+        }
+        return Ok(songs);
+    }
+    if let Some(captures) = USER_PLAYLIST_NAME_REGEX.captures(&query) {
+        tracing::debug!("Query is a private playlist of the author");
+        let playlist = get_user_playlist(database, msg.author.id, &captures[1]).await?;
+        return get_user_playlist_songs(database, &playlist).await;
+    }
     if let Some(captures) = PLAYLIST_NAME_REGEX.captures(&query) {
         tracing::debug!("Query is a saved playlist");
         let pl_name: &str = captures.get(1).unwrap().as_str();
         tracing::trace!("Playlist name is {}", pl_name);
+
+        if let Some(playlist) = database.get_playlist(guild_id.0, pl_name).await? {
+            tracing::debug!("Using stored songs of playlist");
+            return get_playlist_songs(database, &playlist).await;
+        }
         let playlist_opt = database
             .get_guild_playlist(guild_id.0, pl_name.to_string())
             .await?;
@@ -148,71 +315,299 @@ async fn get_songs_for_query(ctx: &Context, msg: &Message, query: &str) -> BotRe
         if songs.len() == 0 {
             tracing::debug!("Query is youtube video");
             let mut song: Song = get_video_information(&query).await?.into();
-            added_one_msg(&ctx, msg, &mut song).await?;
             add_youtube_song_to_database(&store, &database, &mut song).await?;
             songs.push(song);
         } else {
             tracing::debug!("Query is playlist with {} songs", songs.len());
-            added_multiple_msg(&ctx, msg, &mut songs).await?;
         }
     } else if SPOTIFY_PLAYLIST_REGEX.is_match(&query) {
         // search for all songs in the playlist and search for them on youtube
         tracing::debug!("Query is spotify playlist");
-        let tracks = store.spotify_api.get_songs_in_playlist(&query).await?;
-
-        let futures: Vec<BoxFuture<Song>> = tracks
+        // the tracks are resolved to youtube videos when they're added to the queue
+        let items = store.spotify_api.get_songs_in_playlist(&query).await?;
+        songs = items
             .into_iter()
-            .map(|track| {
-                async {
-                    get_youtube_song_for_track(&database, track.clone())
-                        .await
-                        .unwrap_or(None)
-                        .unwrap_or(track.into())
-                }
-                .boxed()
+            .map(|item| match item {
+                SpotifyItem::Track(track) => track.into(),
+                SpotifyItem::Episode(episode) => episode.into(),
             })
             .collect();
-        songs = futures::future::join_all(futures).await;
-
-        added_multiple_msg(&ctx, msg, &mut songs).await?;
     } else if SPOTIFY_ALBUM_REGEX.is_match(&query) {
         // fetch all songs in the album and search for them on youtube
         tracing::debug!("Query is spotify album");
-        let tracks = store.spotify_api.get_songs_in_album(&query).await?;
-
-        for track in tracks {
-            songs.push(
-                get_youtube_song_for_track(&database, track.clone())
-                    .await?
-                    .unwrap_or(track.into()),
-            )
-        }
-
-        added_multiple_msg(&ctx, msg, &mut songs).await?;
+        songs = store
+            .spotify_api
+            .get_songs_in_album(&query)
+            .await?
+            .into_iter()
+            .map(Song::from)
+            .collect();
+    } else if SPOTIFY_ARTIST_REGEX.is_match(&query) {
+        // fetch the top tracks or all tracks of the artist and search for them on youtube
+        let tracks = if discography {
+            tracing::debug!("Query is spotify artist discography");
+            store
+                .spotify_api
+                .get_artist_discography(&query, DISCOGRAPHY_ALBUM_LIMIT, DISCOGRAPHY_TRACK_LIMIT)
+                .await?
+        } else {
+            tracing::debug!("Query is spotify artist");
+            store.spotify_api.get_artist_top_tracks(&query).await?
+        };
+        songs = tracks.into_iter().map(Song::from).collect();
     } else if SPOTIFY_SONG_REGEX.is_match(&query) {
         // fetch the song name and search it on youtube
         tracing::debug!("Query is a spotify song");
         let track = store.spotify_api.get_track_for_url(&query).await?;
-        let mut song = get_youtube_song_for_track(&database, track.clone())
+        songs.push(track.into());
+    } else if SPOTIFY_SHOW_REGEX.is_match(&query) {
+        tracing::debug!("Query is a spotify podcast show");
+        let (show, episodes) = store
+            .spotify_api
+            .get_show_episodes(&query, SHOW_EPISODE_LIMIT)
+            .await?;
+        songs = episodes
+            .into_iter()
+            .map(|e| Song::from_episode(e, show.clone()))
+            .collect();
+    } else if SPOTIFY_EPISODE_REGEX.is_match(&query) {
+        tracing::debug!("Query is a spotify podcast episode");
+        let episode = store.spotify_api.get_episode_for_url(&query).await?;
+        songs.push(episode.into());
+    } else if HTTP_URL_REGEX.is_match(&query) && !url::is_remote_http(&query) {
+        tracing::debug!("Query is an url of a local or private host");
+        return Err(BotError::from("Only urls of public hosts can be played"));
+    } else if HTTP_URL_REGEX.is_match(&query) && url::is_audio(&query) {
+        tracing::debug!("Query is a direct audio url");
+        let domain = url::get_domain_for_url(&query).unwrap_or_default();
+        songs.push(get_direct_song(&query, domain).await);
+    } else if HTTP_URL_REGEX.is_match(&query) {
+        tracing::debug!("Query is an url for another site");
+        songs = youtube_dl::get_extractor_information(&query)
             .await?
-            .unwrap_or(track.into());
-        added_one_msg(ctx, msg, &mut song).await?;
-        songs.push(song);
+            .into_iter()
+            .map(Song::from)
+            .collect();
+
+        if songs.is_empty() {
+            return Err(BotError::Msg(format!("Nothing found for {}", query)));
+        }
     } else {
         tracing::debug!("Query is a youtube search");
-        let mut song: Song = youtube_dl::search_video_information(query.clone())
+        let song: Song = youtube_dl::search_video_information(query.clone())
             .await?
             .ok_or(BotError::Msg(format!("Noting found for {}", query)))?
             .into();
         tracing::trace!("Search result is {:?}", song);
 
-        added_one_msg(&ctx, msg, &mut song).await?;
         songs.push(song);
     }
 
     Ok(songs)
 }
 
+/// Adds the songs to the end of the queue respecting the queue limits
+/// and starts playback if nothing is playing yet.
+/// Songs are added progressively while they're being resolved
+pub async fn enqueue_songs(
+    ctx: &Context,
+    msg: &Message,
+    player: Arc<Mutex<MusicPlayer>>,
+    songs: Vec<Song>,
+) -> BotResult<()> {
+    let guild_id = msg.guild_id.unwrap();
+    let songs = apply_queue_limits(ctx, msg, &player, songs).await?;
+
+    if songs.is_empty() {
+        return Ok(());
+    }
+    let fair_queue = get_setting(ctx, guild_id, Setting::MusicFairQueue)
+        .await?
+        .unwrap_or(false);
+    player.lock().await.queue().set_fair(fair_queue);
+
+    let resolver = SongResolver::for_guild(ctx, guild_id).await?;
+    let total = songs.len();
+    let mut resolved = resolver
+        .resolve(songs)
+        .ready_chunks(resolver.concurrency());
+    let mut progress_msg = None;
+    let mut last_progress = Instant::now();
+    let mut added = 0;
+
+    while let Some(mut chunk) = resolved.next().await {
+        if get_music_player_for_guild(ctx, guild_id).await.is_none() {
+            tracing::debug!("Player was stopped while adding songs");
+            return Ok(());
+        }
+        added += chunk.len();
+
+        if total == 1 {
+            added_one_msg(ctx, msg, &mut chunk[0]).await?;
+        }
+        let (play_first, create_now_playing) = {
+            tracing::debug!("Adding {} songs to queue", chunk.len());
+            let mut player_lock = player.lock().await;
+
+            for mut song in chunk {
+                song.set_requester(msg.author.id);
+                player_lock.queue().add(song);
+            }
+            (
+                player_lock.queue().current().is_none(),
+                player_lock.now_playing_message().is_none(),
+            )
+        };
+
+        if play_first {
+            tracing::debug!("Playing first song in queue");
+            let mut player_lock = player.lock().await;
+            player_lock.play_next().await?;
+        }
+        if create_now_playing {
+            let handle = create_now_playing_msg(ctx, Arc::clone(&player), msg.channel_id).await?;
+            let mut player_lock = player.lock().await;
+            player_lock.set_now_playing(handle).await;
+        }
+        if total > 1
+            && (progress_msg.is_none()
+                || added == total
+                || last_progress.elapsed() >= PROGRESS_UPDATE_INTERVAL)
+        {
+            progress_msg =
+                Some(added_multiple_msg(ctx, msg.channel_id, progress_msg, added, total).await?);
+            last_progress = Instant::now();
+        }
+    }
+    let autoshuffle = get_setting(ctx, guild_id, Setting::MusicAutoShuffle)
+        .await?
+        .unwrap_or(ShuffleMode::Off);
+    let mut player_lock = player.lock().await;
+
+    if autoshuffle != ShuffleMode::Off {
+        tracing::debug!("Autoshuffeling with mode {}", autoshuffle);
+        player_lock.queue().shuffle_with_mode(autoshuffle);
+    }
+    player_lock.on_queue_changed().await?;
+
+    Ok(())
+}
+
+/// Returns how many of the given number of songs the author can add to the queue
+/// at once according to the queue limits unless the author has the dj role
+pub async fn queue_capacity(
+    ctx: &Context,
+    msg: &Message,
+    player: &Arc<Mutex<MusicPlayer>>,
+    count: usize,
+) -> BotResult<usize> {
+    let guild_id = msg.guild_id.unwrap();
+    let dj_role = get_setting::<String>(ctx, guild_id, Setting::MusicDjRole).await?;
+
+    if dj_role.is_some() && is_dj(ctx, guild_id, &msg.author).await? {
+        return Ok(count);
+    }
+    let limits = QueueLimits::for_guild(ctx, guild_id).await?;
+
+    if !limits.allows_count(count) {
+        return Ok(0);
+    }
+    let capacity = {
+        let mut player = player.lock().await;
+        limits.remaining_capacity(player.queue(), msg.author.id)
+    };
+
+    Ok(capacity.map(|c| c.min(count)).unwrap_or(count))
+}
+
+/// Applies the queue limits of the guild to the songs unless the author has the dj role.
+/// Sends a summary of the rejected songs and returns the accepted ones
+async fn apply_queue_limits(
+    ctx: &Context,
+    msg: &Message,
+    player: &Arc<Mutex<MusicPlayer>>,
+    songs: Vec<Song>,
+) -> BotResult<Vec<Song>> {
+    let guild_id = msg.guild_id.unwrap();
+    let dj_role = get_setting::<String>(ctx, guild_id, Setting::MusicDjRole).await?;
+
+    if dj_role.is_some() && is_dj(ctx, guild_id, &msg.author).await? {
+        return Ok(songs);
+    }
+    let limits = QueueLimits::for_guild(ctx, guild_id).await?;
+    // fetched without holding the lock as it runs youtube-dl for every unknown duration
+    let songs = if limits.limits_duration() && limits.allows_count(songs.len()) {
+        let capacity = {
+            let mut player = player.lock().await;
+            limits.remaining_capacity(player.queue(), msg.author.id)
+        };
+        let resolver = SongResolver::for_guild(ctx, guild_id).await?;
+        fetch_durations_within_capacity(&resolver, &limits, songs, capacity).await
+    } else {
+        songs
+    };
+    let (accepted, rejected) = {
+        let mut player = player.lock().await;
+        limits.apply(player.queue(), msg.author.id, songs)
+    };
+
+    if !rejected.is_empty() {
+        tracing::debug!("{} songs were rejected", rejected.len());
+        msg.channel_id
+            .send_message(&ctx.http, |m| {
+                m.embed(|e| e.description(rejection_summary(&rejected)))
+            })
+            .await?;
+    }
+
+    Ok(accepted)
+}
+
+/// Fetches the unknown durations of the songs in chunks until enough songs
+/// within the maximum duration were found to fill the remaining capacity.
+/// The songs after that are rejected anyway so their durations aren't fetched
+async fn fetch_durations_within_capacity(
+    resolver: &SongResolver,
+    limits: &QueueLimits,
+    songs: Vec<Song>,
+    capacity: Option<usize>,
+) -> Vec<Song> {
+    let capacity = if let Some(capacity) = capacity {
+        capacity
+    } else {
+        return resolver.fetch_durations(songs).await;
+    };
+    let mut remaining = songs.into_iter();
+    let mut fetched = Vec::new();
+    let mut fitting = 0;
+
+    while fitting < capacity {
+        let chunk_size = (capacity - fitting).min(resolver.concurrency()).max(1);
+        let chunk: Vec<Song> = remaining.by_ref().take(chunk_size).collect();
+
+        if chunk.is_empty() {
+            break;
+        }
+        let chunk = resolver.fetch_durations(chunk).await;
+        fitting += chunk.iter().filter(|s| !limits.exceeds_duration(s)).count();
+        fetched.extend(chunk);
+    }
+    fetched.extend(remaining);
+
+    fetched
+}
+
+/// Message about the songs added to the queue
+async fn added_songs_msg(ctx: &Context, msg: &Message, songs: &mut Vec<Song>) -> BotResult<()> {
+    match songs.len() {
+        0 => Ok(()),
+        1 => added_one_msg(ctx, msg, &mut songs[0]).await,
+        count => added_multiple_msg(ctx, msg.channel_id, None, count, count)
+            .await
+            .map(|_| ()),
+    }
+}
+
 /// Message when one song was added to the queue
 async fn added_one_msg(ctx: &Context, msg: &Message, song: &mut Song) -> BotResult<()> {
     let url = song.url().await.ok_or(BotError::from("Song not found"))?;
@@ -231,14 +626,32 @@ async fn added_one_msg(ctx: &Context, msg: &Message, song: &mut Song) -> BotResu
     Ok(())
 }
 
-/// Message when multiple songs were added to the queue
-async fn added_multiple_msg(ctx: &Context, msg: &Message, songs: &mut Vec<Song>) -> BotResult<()> {
-    msg.channel_id
-        .send_message(&ctx.http, |m| {
-            m.embed(|e| e.description(format!("Added {} songs to the queue", songs.len())))
-        })
-        .await?;
-    Ok(())
+/// Message when multiple songs were added to the queue.
+/// The given message is edited to show the progress if there's one
+async fn added_multiple_msg(
+    ctx: &Context,
+    channel_id: ChannelId,
+    message: Option<Message>,
+    added: usize,
+    total: usize,
+) -> BotResult<Message> {
+    let description = if added < total {
+        format!("Adding songs to the queue... ({}/{})", added, total)
+    } else {
+        format!("Added {} songs to the queue", total)
+    };
+
+    if let Some(mut message) = message {
+        message
+            .edit(&ctx.http, |m| m.embed(|e| e.description(description)))
+            .await?;
+        Ok(message)
+    } else {
+        let message = channel_id
+            .send_message(&ctx.http, |m| m.embed(|e| e.description(description)))
+            .await?;
+        Ok(message)
+    }
 }
 
 #[check]
@@ -282,26 +695,65 @@ pub async fn is_dj(ctx: &Context, guild: GuildId, user: &User) -> BotResult<bool
     }
 }
 
-/// Searches for a matching youtube song for the given track in the local database
-async fn get_youtube_song_for_track(database: &Database, track: Track) -> BotResult<Option<Song>> {
-    tracing::debug!("Trying to find track in database.");
-    if let Some(id) = track.id {
-        let entry = database.get_song(&id).await?;
-
-        if let Some(song) = entry {
-            // check if the video is still available
-            tracing::trace!("Found entry is {:?}", song);
-            if let Ok(info) = get_video_information(&song.url).await {
-                return Ok(Some(info.into()));
-            } else {
-                tracing::debug!("Video '{}' is not available. Deleting entry", song.url);
-                database.delete_song(song.id).await?;
-                return Ok(None);
-            }
-        }
-        Ok(None)
+/// The outcome of a request to skip the current song
+pub enum SkipResult {
+    /// The song was skipped
+    Skipped,
+    /// The vote was registered but more votes are required
+    Voted { votes: usize, required: usize },
+    /// The user isn't listening in the players voice channel
+    NotListening,
+    /// The user is not a dj and vote skipping is disabled
+    NotAllowed,
+}
+
+/// Skips the current song if the user is a dj or registers
+/// a vote to skip if vote skipping is enabled for the guild.
+/// The song gets skipped once the configured ratio of listeners voted for it
+pub async fn skip_or_vote(
+    ctx: &Context,
+    guild_id: GuildId,
+    user: &User,
+    player: Arc<Mutex<MusicPlayer>>,
+) -> BotResult<SkipResult> {
+    if is_dj(ctx, guild_id, user).await? {
+        player.lock().await.skip().await?;
+        return Ok(SkipResult::Skipped);
+    }
+    let ratio = match get_setting::<f32>(ctx, guild_id, Setting::MusicVoteskipRatio).await? {
+        Some(ratio) => ratio.max(0.0).min(1.0),
+        None => return Ok(SkipResult::NotAllowed),
+    };
+    let voice_channel = player.lock().await.voice_channel();
+    let guild = ctx.cache.guild(guild_id);
+    let user_channel = guild
+        .as_ref()
+        .and_then(|g| get_channel_for_author(&user.id, g).ok());
+
+    if user_channel != Some(voice_channel) {
+        return Ok(SkipResult::NotListening);
+    }
+    let listener_ids: HashSet<UserId> = guild
+        .iter()
+        .flat_map(|g| g.voice_states.values())
+        .filter(|state| state.channel_id == Some(voice_channel))
+        .map(|state| state.user_id)
+        .collect();
+    let listeners = get_own_channel_member_count(ctx, voice_channel)
+        .await
+        .unwrap_or(1);
+    let required = ((listeners as f32 * ratio).ceil() as usize).max(1);
+    tracing::debug!("{} of {} listeners need to vote", required, listeners);
+
+    let mut player = player.lock().await;
+    let votes = player.vote_skip(user.id, &listener_ids, required);
+
+    if votes >= required {
+        player.skip().await?;
+        Ok(SkipResult::Skipped)
     } else {
-        tracing::debug!("Track has no ID");
-        Ok(None)
+        player.update_now_playing().await?;
+        Ok(SkipResult::Voted { votes, required })
     }
 }
+
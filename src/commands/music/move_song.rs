@@ -34,6 +34,7 @@ async fn move_song(ctx: &Context, msg: &Message, mut args: Args) -> CommandResul
     {
         let mut player = player.lock().await;
         player.queue().move_position(pos1, pos2);
+        player.on_queue_changed().await?;
     }
     EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| {
         m.content(format!(
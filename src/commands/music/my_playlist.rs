@@ -0,0 +1,57 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::my_playlist_add::MY_PLAYLIST_ADD_COMMAND;
+use crate::commands::music::my_playlist_delete::MY_PLAYLIST_DELETE_COMMAND;
+use crate::commands::music::my_playlist_remove::MY_PLAYLIST_REMOVE_COMMAND;
+use crate::commands::music::my_playlist_save_queue::MY_PLAYLIST_SAVE_QUEUE_COMMAND;
+use crate::messages::music::playlists::create_playlists_menu;
+use crate::messages::music::queue::create_playlist_menu;
+use crate::providers::music::playlists::{get_user_playlist, get_user_playlist_songs};
+use crate::utils::context_data::get_database_from_context;
+
+#[command("myplaylist")]
+#[description("Shows the songs of one of your private playlists or lists all of them. Use `play my:<name>` to play them in any guild")]
+#[usage("(<name>|save-queue <name>|add <name> <query>|remove <name> <index>|delete <name>)")]
+#[example("workout")]
+#[max_args(1)]
+#[aliases("mypl", "my-playlist")]
+#[bucket("general")]
+#[sub_commands(
+    my_playlist_save_queue,
+    my_playlist_add,
+    my_playlist_remove,
+    my_playlist_delete
+)]
+async fn my_playlist(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let database = get_database_from_context(ctx).await;
+
+    if args.is_empty() {
+        tracing::debug!("Displaying playlists of user {}", msg.author.id);
+        let mut entries = Vec::new();
+
+        for playlist in database.get_user_playlists(msg.author.id.0).await? {
+            let count = database.get_user_playlist_song_count(playlist.id).await?;
+            entries.push(format!("**{}** ({} songs)", playlist.name, count));
+        }
+        create_playlists_menu(ctx, msg.channel_id, entries).await?;
+        handle_autodelete(ctx, msg).await?;
+        return Ok(());
+    }
+    let name: String = args.single()?;
+    tracing::debug!("Displaying playlist {} of user {}", name, msg.author.id);
+    let playlist = get_user_playlist(&database, msg.author.id, &name).await?;
+    let songs = get_user_playlist_songs(&database, &playlist)
+        .await?
+        .into_iter()
+        .enumerate()
+        .collect();
+
+    create_playlist_menu(ctx, msg.channel_id, &playlist.name, songs).await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::get_songs_for_query;
+use crate::providers::music::playlists::{get_user_playlist_songs, set_user_playlist_songs};
+use crate::utils::context_data::get_database_from_context;
+
+#[command("add")]
+#[only_in(guilds)]
+#[description("Adds songs to one of your private playlists creating it if it doesn't exist")]
+#[usage("<name> (<spotify_url>|<youtube_url>|<audio_url>|<query>)")]
+#[example("workout eye of the tiger")]
+#[min_args(2)]
+#[bucket("music_api")]
+async fn my_playlist_add(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let name: String = args.single()?;
+    let query = args.rest();
+    tracing::debug!(
+        "Adding '{}' to playlist {} of user {}",
+        query,
+        name,
+        msg.author.id
+    );
+
+    let database = get_database_from_context(ctx).await;
+    let added = get_songs_for_query(ctx, msg, query).await?;
+    let playlist = if let Some(playlist) = database.get_user_playlist(msg.author.id.0, &name).await? {
+        playlist
+    } else {
+        database
+            .create_user_playlist(msg.author.id.0, name.clone())
+            .await?
+    };
+    let count = added.len();
+    let mut songs = get_user_playlist_songs(&database, &playlist).await?;
+    songs.extend(added);
+    set_user_playlist_songs(&database, &playlist, songs).await?;
+
+    msg.channel_id
+        .say(
+            ctx,
+            format!("Added {} songs to your playlist **{}**", count, name),
+        )
+        .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::providers::music::playlists::get_user_playlist;
+use crate::utils::context_data::get_database_from_context;
+
+#[command("delete")]
+#[description("Deletes one of your private playlists")]
+#[usage("<name>")]
+#[example("workout")]
+#[num_args(1)]
+#[bucket("general")]
+async fn my_playlist_delete(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let name: String = args.single()?;
+    tracing::debug!("Deleting playlist {} of user {}", name, msg.author.id);
+    let database = get_database_from_context(ctx).await;
+    let playlist = get_user_playlist(&database, msg.author.id, &name).await?;
+    database.delete_user_playlist(playlist.id).await?;
+
+    msg.channel_id
+        .say(ctx, format!("Deleted your playlist **{}**", name))
+        .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::providers::music::playlists::{
+    get_user_playlist, get_user_playlist_songs, set_user_playlist_songs,
+};
+use crate::utils::context_data::get_database_from_context;
+
+#[command("remove")]
+#[description("Removes a song from one of your private playlists")]
+#[usage("<name> <index>")]
+#[example("workout 3")]
+#[num_args(2)]
+#[bucket("general")]
+async fn my_playlist_remove(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let name: String = args.single()?;
+    let index: usize = args.single()?;
+    tracing::debug!(
+        "Removing song {} from playlist {} of user {}",
+        index,
+        name,
+        msg.author.id
+    );
+
+    let database = get_database_from_context(ctx).await;
+    let playlist = get_user_playlist(&database, msg.author.id, &name).await?;
+    let mut songs = get_user_playlist_songs(&database, &playlist).await?;
+
+    if index >= songs.len() {
+        msg.channel_id
+            .say(
+                ctx,
+                format!("The playlist has no song at position {}", index),
+            )
+            .await?;
+        return Ok(());
+    }
+    let song = songs.remove(index);
+    set_user_playlist_songs(&database, &playlist, songs).await?;
+
+    msg.channel_id
+        .say(
+            ctx,
+            format!(
+                "Removed **{}** from your playlist **{}**",
+                song.title(),
+                name
+            ),
+        )
+        .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
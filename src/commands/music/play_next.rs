@@ -5,17 +5,18 @@ use serenity::model::channel::Message;
 
 use crate::commands::common::handle_autodelete;
 use crate::commands::music::{
-    get_channel_for_author, get_music_player_for_guild, get_songs_for_query, DJ_CHECK,
+    added_songs_msg, apply_queue_limits, get_channel_for_author, get_music_player_for_guild,
+    get_songs_for_query, DJ_CHECK,
 };
 use crate::messages::music::now_playing::create_now_playing_msg;
 use crate::providers::music::player::MusicPlayer;
+use crate::providers::music::resolver::SongResolver;
 use std::sync::Arc;
 
 #[command]
 #[only_in(guilds)]
 #[description("Puts a song as the next to play in the queue")]
-#[usage("(<spotify_ur>|<youtube_url>|<query>|pl:<saved_playlist>)")]
-#[min_args(1)]
+#[usage("(<spotify_ur>|<youtube_url>|<audio_url>|<query>|pl:<saved_playlist>|my:<private_playlist>|fav:|(--discography) <spotify_artist_url>|<audio_attachment>)")]
 #[aliases("pn", "play-next", "playnext")]
 #[bucket("music_api")]
 #[checks(DJ)]
@@ -35,16 +36,27 @@ async fn play_next(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
     }
 
     let player = player.unwrap();
-    let mut songs = get_songs_for_query(&ctx, msg, query).await?;
+    let songs = get_songs_for_query(&ctx, msg, query).await?;
+    let songs = apply_queue_limits(ctx, msg, &player, songs).await?;
+
+    if songs.is_empty() {
+        handle_autodelete(ctx, msg).await?;
+        return Ok(());
+    }
+    let resolver = SongResolver::for_guild(ctx, guild.id).await?;
+    let mut songs = resolver.resolve_all(songs).await;
+    added_songs_msg(ctx, msg, &mut songs).await?;
 
     let (play_first, create_now_playing) = {
         let mut player_lock = player.lock().await;
         songs.reverse();
         tracing::debug!("Enqueueing songs as next songs in the queue");
 
-        for song in songs {
+        for mut song in songs {
+            song.set_requester(msg.author.id);
             player_lock.queue().add_next(song);
         }
+        player_lock.on_queue_changed().await?;
         (
             player_lock.queue().current().is_none(),
             player_lock.now_playing_message().is_none(),
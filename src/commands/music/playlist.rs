@@ -0,0 +1,56 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::playlist_add::PLAYLIST_ADD_COMMAND;
+use crate::commands::music::playlist_delete::PLAYLIST_DELETE_COMMAND;
+use crate::commands::music::playlist_move::PLAYLIST_MOVE_COMMAND;
+use crate::commands::music::playlist_remove::PLAYLIST_REMOVE_COMMAND;
+use crate::commands::music::playlist_rename::PLAYLIST_RENAME_COMMAND;
+use crate::commands::music::playlist_save_queue::PLAYLIST_SAVE_QUEUE_COMMAND;
+use crate::commands::music::playlists::show_playlists;
+use crate::messages::music::queue::create_playlist_menu;
+use crate::providers::music::playlists::{get_playlist, get_playlist_songs};
+use crate::utils::context_data::get_database_from_context;
+
+#[command]
+#[only_in(guilds)]
+#[description("Shows the songs of a saved playlist or lists all playlists")]
+#[usage("(<name>|save-queue <name>|add <name> <query>|remove <name> <index>|move <name> <from> <to>|rename <name> <new_name>|delete <name>)")]
+#[example("chill")]
+#[max_args(1)]
+#[bucket("general")]
+#[sub_commands(
+    playlist_save_queue,
+    playlist_add,
+    playlist_remove,
+    playlist_move,
+    playlist_rename,
+    playlist_delete
+)]
+async fn playlist(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+
+    if args.is_empty() {
+        tracing::debug!("Displaying playlists for guild {}", guild.id);
+        show_playlists(ctx, msg, guild.id).await?;
+        handle_autodelete(ctx, msg).await?;
+        return Ok(());
+    }
+    let name: String = args.single()?;
+    tracing::debug!("Displaying playlist {} for guild {}", name, guild.id);
+    let database = get_database_from_context(ctx).await;
+    let playlist = get_playlist(&database, guild.id, &name).await?;
+    let songs = get_playlist_songs(&database, &playlist)
+        .await?
+        .into_iter()
+        .enumerate()
+        .collect();
+
+    create_playlist_menu(ctx, msg.channel_id, &playlist.name, songs).await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::{get_songs_for_query, DJ_CHECK};
+use crate::providers::music::playlists::{get_playlist_songs, set_playlist_songs};
+use crate::utils::context_data::get_database_from_context;
+
+#[command("add")]
+#[only_in(guilds)]
+#[description("Adds songs to a playlist creating it if it doesn't exist")]
+#[usage("<name> (<spotify_url>|<youtube_url>|<audio_url>|<query>)")]
+#[example("chill never gonna give you up")]
+#[min_args(2)]
+#[bucket("music_api")]
+#[checks(DJ)]
+async fn playlist_add(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    let name: String = args.single()?;
+    let query = args.rest();
+    tracing::debug!("Adding '{}' to playlist {} for guild {}", query, name, guild.id);
+
+    let database = get_database_from_context(ctx).await;
+    let added = get_songs_for_query(ctx, msg, query).await?;
+    let playlist = if let Some(playlist) = database.get_playlist(guild.id.0, &name).await? {
+        playlist
+    } else {
+        database.create_playlist(guild.id.0, name.clone()).await?
+    };
+    let count = added.len();
+    let mut songs = get_playlist_songs(&database, &playlist).await?;
+    songs.extend(added);
+    set_playlist_songs(&database, &playlist, songs).await?;
+
+    msg.channel_id
+        .say(ctx, format!("Added {} songs to playlist **{}**", count, name))
+        .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
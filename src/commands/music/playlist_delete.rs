@@ -0,0 +1,45 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::DJ_CHECK;
+use crate::utils::context_data::get_database_from_context;
+
+#[command("delete")]
+#[only_in(guilds)]
+#[description("Deletes a saved playlist")]
+#[usage("<name>")]
+#[example("chill")]
+#[num_args(1)]
+#[bucket("general")]
+#[checks(DJ)]
+async fn playlist_delete(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    let name: String = args.single()?;
+    tracing::debug!("Deleting playlist {} for guild {}", name, guild.id);
+    let database = get_database_from_context(ctx).await;
+
+    if let Some(playlist) = database.get_playlist(guild.id.0, &name).await? {
+        database.delete_playlist(playlist.id).await?;
+    } else if database
+        .get_guild_playlist(guild.id.0, name.clone())
+        .await?
+        .is_some()
+    {
+        database.delete_guild_playlist(guild.id.0, name.clone()).await?;
+    } else {
+        msg.channel_id
+            .say(ctx, format!("There's no playlist named **{}**", name))
+            .await?;
+        return Ok(());
+    }
+
+    msg.channel_id
+        .say(ctx, format!("Deleted playlist **{}**", name))
+        .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::DJ_CHECK;
+use crate::providers::music::playlists::{get_playlist, get_playlist_songs, set_playlist_songs};
+use crate::utils::context_data::get_database_from_context;
+
+#[command("move")]
+#[only_in(guilds)]
+#[description("Moves a song of a playlist to a different position")]
+#[usage("<name> <from> <to>")]
+#[example("chill 5 0")]
+#[num_args(3)]
+#[bucket("general")]
+#[checks(DJ)]
+async fn playlist_move(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    let name: String = args.single()?;
+    let from: usize = args.single()?;
+    let to: usize = args.single()?;
+    tracing::debug!(
+        "Moving song {} to {} in playlist {} for guild {}",
+        from,
+        to,
+        name,
+        guild.id
+    );
+
+    let database = get_database_from_context(ctx).await;
+    let playlist = get_playlist(&database, guild.id, &name).await?;
+    let mut songs = get_playlist_songs(&database, &playlist).await?;
+
+    if from >= songs.len() || to >= songs.len() {
+        msg.channel_id
+            .say(
+                ctx,
+                format!("The playlist has no song at position {}", from.max(to)),
+            )
+            .await?;
+        return Ok(());
+    }
+    let song = songs.remove(from);
+    let title = song.title().clone();
+    songs.insert(to, song);
+    set_playlist_songs(&database, &playlist, songs).await?;
+
+    msg.channel_id
+        .say(ctx, format!("Moved **{}** to position {}", title, to))
+        .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
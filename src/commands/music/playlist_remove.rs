@@ -0,0 +1,55 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::DJ_CHECK;
+use crate::providers::music::playlists::{get_playlist, get_playlist_songs, set_playlist_songs};
+use crate::utils::context_data::get_database_from_context;
+
+#[command("remove")]
+#[only_in(guilds)]
+#[description("Removes a song from a playlist")]
+#[usage("<name> <index>")]
+#[example("chill 3")]
+#[num_args(2)]
+#[bucket("general")]
+#[checks(DJ)]
+async fn playlist_remove(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    let name: String = args.single()?;
+    let index: usize = args.single()?;
+    tracing::debug!(
+        "Removing song {} from playlist {} for guild {}",
+        index,
+        name,
+        guild.id
+    );
+
+    let database = get_database_from_context(ctx).await;
+    let playlist = get_playlist(&database, guild.id, &name).await?;
+    let mut songs = get_playlist_songs(&database, &playlist).await?;
+
+    if index >= songs.len() {
+        msg.channel_id
+            .say(
+                ctx,
+                format!("The playlist has no song at position {}", index),
+            )
+            .await?;
+        return Ok(());
+    }
+    let song = songs.remove(index);
+    set_playlist_songs(&database, &playlist, songs).await?;
+
+    msg.channel_id
+        .say(
+            ctx,
+            format!("Removed **{}** from playlist **{}**", song.title(), name),
+        )
+        .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::DJ_CHECK;
+use crate::utils::context_data::get_database_from_context;
+
+#[command("rename")]
+#[only_in(guilds)]
+#[description("Renames a saved playlist")]
+#[usage("<name> <new_name>")]
+#[example("chill lofi")]
+#[num_args(2)]
+#[bucket("general")]
+#[checks(DJ)]
+async fn playlist_rename(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    let name: String = args.single()?;
+    let new_name: String = args.single()?;
+    tracing::debug!(
+        "Renaming playlist {} to {} for guild {}",
+        name,
+        new_name,
+        guild.id
+    );
+    let database = get_database_from_context(ctx).await;
+
+    if database.get_playlist(guild.id.0, &new_name).await?.is_some()
+        || database
+            .get_guild_playlist(guild.id.0, new_name.clone())
+            .await?
+            .is_some()
+    {
+        msg.channel_id
+            .say(ctx, format!("A playlist named **{}** already exists", new_name))
+            .await?;
+        return Ok(());
+    }
+
+    if let Some(playlist) = database.get_playlist(guild.id.0, &name).await? {
+        database.rename_playlist(playlist.id, new_name.clone()).await?;
+    } else if let Some(playlist) = database.get_guild_playlist(guild.id.0, name.clone()).await? {
+        database
+            .add_guild_playlist(guild.id.0, new_name.clone(), playlist.url)
+            .await?;
+        database.delete_guild_playlist(guild.id.0, name.clone()).await?;
+    } else {
+        msg.channel_id
+            .say(ctx, format!("There's no playlist named **{}**", name))
+            .await?;
+        return Ok(());
+    }
+
+    msg.channel_id
+        .say(ctx, format!("Renamed **{}** to **{}**", name, new_name))
+        .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
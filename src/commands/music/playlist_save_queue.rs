@@ -0,0 +1,62 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandError, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::{get_music_player_for_guild, DJ_CHECK};
+use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use crate::providers::music::playlists::set_playlist_songs;
+use crate::providers::music::queue::Song;
+use crate::utils::context_data::get_database_from_context;
+
+#[command("save-queue")]
+#[only_in(guilds)]
+#[description("Saves the current song and the queue as a playlist replacing an existing one")]
+#[usage("<name>")]
+#[example("chill")]
+#[num_args(1)]
+#[bucket("general")]
+#[checks(DJ)]
+async fn playlist_save_queue(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    let name: String = args.single()?;
+    tracing::debug!("Saving queue as playlist {} for guild {}", name, guild.id);
+
+    let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
+        player
+    } else {
+        return create_no_voicechannel_message(&ctx.http, msg.channel_id)
+            .await
+            .map_err(CommandError::from);
+    };
+    let songs: Vec<Song> = {
+        let mut player = player.lock().await;
+        let queue = player.queue();
+        queue
+            .current()
+            .iter()
+            .chain(queue.entries().iter())
+            .cloned()
+            .collect()
+    };
+    if songs.is_empty() {
+        msg.channel_id.say(ctx, "The queue is empty").await?;
+        return Ok(());
+    }
+    let database = get_database_from_context(ctx).await;
+    let playlist = if let Some(playlist) = database.get_playlist(guild.id.0, &name).await? {
+        playlist
+    } else {
+        database.create_playlist(guild.id.0, name.clone()).await?
+    };
+    let count = songs.len();
+    set_playlist_songs(&database, &playlist, songs).await?;
+
+    msg.channel_id
+        .say(ctx, format!("Saved {} songs as playlist **{}**", count, name))
+        .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
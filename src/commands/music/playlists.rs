@@ -2,9 +2,12 @@ use serenity::client::Context;
 use serenity::framework::standard::macros::command;
 use serenity::framework::standard::CommandResult;
 use serenity::model::channel::Message;
+use serenity::model::id::GuildId;
 
 use crate::commands::common::handle_autodelete;
+use crate::messages::music::playlists::create_playlists_menu;
 use crate::utils::context_data::get_database_from_context;
+use crate::utils::error::BotResult;
 
 #[command]
 #[only_in(guilds)]
@@ -14,23 +17,28 @@ use crate::utils::context_data::get_database_from_context;
 async fn playlists(ctx: &Context, msg: &Message) -> CommandResult {
     let guild = msg.guild(&ctx.cache).unwrap();
     tracing::debug!("Displaying playlists for guild {}", guild.id);
-    let database = get_database_from_context(ctx).await;
-
-    let playlists = database.get_guild_playlists(guild.id.0).await?;
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Saved Playlists").description(
-                    playlists
-                        .into_iter()
-                        .map(|p| format!("[{}]({})", p.name, p.url))
-                        .collect::<Vec<String>>()
-                        .join("\n"),
-                )
-            })
-        })
-        .await?;
+    show_playlists(ctx, msg, guild.id).await?;
     handle_autodelete(ctx, msg).await?;
 
     Ok(())
 }
+
+/// Shows a menu with the stored playlists and the saved playlist urls of the guild
+pub(crate) async fn show_playlists(ctx: &Context, msg: &Message, guild_id: GuildId) -> BotResult<()> {
+    let database = get_database_from_context(ctx).await;
+    let mut entries = Vec::new();
+
+    for playlist in database.get_playlists(guild_id.0).await? {
+        let count = database.get_playlist_song_count(playlist.id).await?;
+        entries.push(format!("**{}** ({} songs)", playlist.name, count));
+    }
+    entries.extend(
+        database
+            .get_guild_playlists(guild_id.0)
+            .await?
+            .into_iter()
+            .map(|p| format!("[{}]({})", p.name, p.url)),
+    );
+
+    create_playlists_menu(ctx, msg.channel_id, entries).await
+}
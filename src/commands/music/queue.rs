@@ -1,9 +1,13 @@
+use std::time::Duration;
+
 use serenity::client::Context;
 use serenity::framework::standard::macros::command;
 use serenity::framework::standard::{Args, CommandError, CommandResult};
 use serenity::model::channel::Message;
 
 use crate::commands::common::handle_autodelete;
+use crate::commands::music::export_queue::EXPORT_QUEUE_COMMAND;
+use crate::commands::music::import_queue::IMPORT_QUEUE_COMMAND;
 use crate::commands::music::get_music_player_for_guild;
 use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
 use crate::messages::music::queue::create_queue_menu;
@@ -12,17 +16,23 @@ use crate::providers::music::queue::Song;
 #[command]
 #[only_in(guilds)]
 #[description("Shows the song queue")]
-#[usage("(<query...>)")]
+#[usage("(<query...>|mine|export (json|m3u|xspf)|import)")]
 #[aliases("q")]
 #[bucket("general")]
+#[sub_commands(export_queue, import_queue)]
 async fn queue(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     let guild = msg.guild(&ctx.cache).unwrap();
     tracing::trace!("Displaying queue for guild {}", guild.id);
 
-    let query = args
+    let mut query = args
         .iter::<String>()
         .map(|s| s.unwrap().to_lowercase())
         .collect::<Vec<String>>();
+    let only_own = query.first().map(|q| q == "mine").unwrap_or(false);
+
+    if only_own {
+        query.remove(0);
+    }
 
     let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
         player
@@ -31,40 +41,55 @@ async fn queue(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
             .await
             .map_err(CommandError::from);
     };
-    let mut player = player.lock().await;
-    let songs: Vec<(usize, Song)> = player
-        .queue()
-        .entries()
-        .into_iter()
-        .enumerate()
-        .filter(|(i, s)| {
-            if query.is_empty() {
-                return true;
-            }
-            for kw in &query {
-                if s.title().to_lowercase().contains(kw)
-                    || s.author().to_lowercase().contains(kw)
-                    || &i.to_string() == kw
-                {
+    let (songs, fair, total_remaining) = {
+        let mut player = player.lock().await;
+        let remaining = player.remaining().await;
+        let starts = player.queue().start_offsets(remaining);
+        let total_remaining = player.queue().remaining_duration(remaining);
+        let songs: Vec<(usize, Song, Option<Duration>)> = player
+            .queue()
+            .entries()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, s)| !only_own || s.requester() == Some(msg.author.id))
+            .filter(|(i, s)| {
+                if query.is_empty() {
                     return true;
                 }
-            }
-            false
-        })
-        .map(|(i, s)| (i, s.clone()))
-        .collect();
+                for kw in &query {
+                    if s.title().to_lowercase().contains(kw)
+                        || s.author().to_lowercase().contains(kw)
+                        || &i.to_string() == kw
+                    {
+                        return true;
+                    }
+                }
+                false
+            })
+            .map(|(i, s)| (i, s.clone(), starts[i]))
+            .collect();
+        let fair = player.queue().is_fair();
+
+        (songs, fair, total_remaining)
+    };
     tracing::trace!("Songs are {:?}", songs);
 
     if songs.len() == 0 {
         msg.channel_id
             .send_message(ctx, |m| {
-                m.embed(|e| e.title("Queue").description("*The queue is empty*"))
+                m.embed(|e| {
+                    e.title("Queue").description(if only_own {
+                        "*You have no songs in the queue*"
+                    } else {
+                        "*The queue is empty*"
+                    })
+                })
             })
             .await?;
 
         return Ok(());
     }
-    create_queue_menu(ctx, msg.channel_id, songs).await?;
+    create_queue_menu(ctx, msg.channel_id, songs, fair, total_remaining).await?;
 
     handle_autodelete(ctx, msg).await?;
 
@@ -1,27 +1,37 @@
+use std::collections::HashSet;
+
 use crate::commands::common::handle_autodelete;
 use crate::commands::music::{get_music_player_for_guild, DJ_CHECK};
 use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use crate::utils::error::BotError;
+use bot_coreutils::selection::parse_selection;
 use serenity::client::Context;
 use serenity::framework::standard::macros::command;
 use serenity::framework::standard::{Args, CommandError, CommandResult};
 use serenity::model::channel::Message;
+use serenity::model::id::UserId;
+use serenity::utils::parse_username;
 use serenity_additions::core::SHORT_TIMEOUT;
 use serenity_additions::ephemeral_message::EphemeralMessage;
 
 #[command]
-#[description("Removes a song from the queue")]
-#[usage("<pos>")]
-#[example("102")]
-#[num_args(1)]
+#[description(
+    "Removes songs from the queue. \
+    Songs can be selected by position, ranges of positions, \
+    the user that requested them or `left` for the songs of users that left the voice channel"
+)]
+#[usage("(<pos>|<from>-<to>,...|@user|left)")]
+#[example("3-7,10")]
+#[min_args(1)]
 #[bucket("general")]
 #[only_in(guilds)]
-#[aliases("rms", "removesong", "remove-song")]
+#[aliases("rms", "removesong", "remove-song", "remove")]
 #[checks(DJ)]
-async fn remove_song(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+async fn remove_song(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
     let guild = msg.guild(&ctx.cache).unwrap();
-    tracing::debug!("Moving song for guild {}", guild.id);
+    tracing::debug!("Removing songs for guild {}", guild.id);
 
-    let pos = args.single::<usize>()?;
+    let selection = args.rest().trim().to_lowercase();
     let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
         player
     } else {
@@ -30,15 +40,42 @@ async fn remove_song(ctx: &Context, msg: &Message, mut args: Args) -> CommandRes
             .map_err(CommandError::from);
     };
 
-    {
+    let removed = {
         let mut player = player.lock().await;
-        player.queue().remove(pos);
-    }
 
-    EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| {
-        m.content(format!("🗑️ Removed Song at `{}`", pos))
-    })
-    .await?;
+        let removed = if selection == "left" {
+            let voice_channel = player.voice_channel();
+            let listeners: HashSet<UserId> = guild
+                .voice_states
+                .values()
+                .filter(|state| state.channel_id == Some(voice_channel))
+                .map(|state| state.user_id)
+                .collect();
+
+            player
+                .queue()
+                .remove_where(|s| matches!(s.requester(), Some(r) if !listeners.contains(&r)))
+        } else if let Some(user_id) = parse_username(&selection) {
+            player
+                .queue()
+                .remove_where(|s| s.requester() == Some(UserId(user_id)))
+        } else {
+            let indices = parse_selection(&selection).ok_or_else(|| {
+                BotError::Msg(format!("`{}` is not a valid selection of songs", selection))
+            })?;
+            player.queue().remove_all(&indices)
+        };
+        player.on_queue_changed().await?;
+        removed
+    };
+
+    let content = match removed.len() {
+        0 => "No songs matched the selection".to_string(),
+        1 => format!("🗑️ Removed **{}**", removed[0].title()),
+        n => format!("🗑️ Removed {} songs. Use `undo` to restore them", n),
+    };
+    EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| m.content(content))
+        .await?;
     handle_autodelete(ctx, msg).await?;
 
     Ok(())
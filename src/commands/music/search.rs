@@ -0,0 +1,25 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::search_songs;
+
+#[command]
+#[only_in(guilds)]
+#[description("Searches for songs on youtube and lets you pick the one to add to the queue")]
+#[usage("<query...>")]
+#[example("never gonna give you up")]
+#[min_args(1)]
+#[aliases("find")]
+#[bucket("music_api")]
+async fn search(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let query = args.message();
+    tracing::debug!("Searching for '{}'", query);
+
+    search_songs(ctx, msg, query).await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
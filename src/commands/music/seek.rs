@@ -0,0 +1,51 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandError, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::{get_music_player_for_guild, DJ_CHECK};
+use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use bot_coreutils::time::{format_duration, parse_duration};
+use serenity_additions::core::SHORT_TIMEOUT;
+use serenity_additions::ephemeral_message::EphemeralMessage;
+
+#[command]
+#[only_in(guilds)]
+#[description("Jumps to a position in the current song")]
+#[usage("<[hh:]mm:ss>")]
+#[example("1:30")]
+#[num_args(1)]
+#[bucket("general")]
+#[checks(DJ)]
+async fn seek(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    tracing::debug!("Seeking in song for guild {}", guild.id);
+
+    let position = crate::forward_error!(
+        ctx,
+        msg.channel_id,
+        parse_duration(&args.single::<String>()?).ok_or("Invalid position")
+    );
+    let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
+        player
+    } else {
+        return create_no_voicechannel_message(&ctx.http, msg.channel_id)
+            .await
+            .map_err(CommandError::from);
+    };
+    let position = {
+        let mut player = player.lock().await;
+        let position = player.seek(position).await?;
+        player.update_now_playing().await?;
+        position
+    };
+
+    EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| {
+        m.content(format!("⏩ Jumped to `{}`", format_duration(position)))
+    })
+    .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
@@ -1,23 +1,41 @@
 use serenity::client::Context;
 use serenity::framework::standard::macros::command;
-use serenity::framework::standard::{CommandError, CommandResult};
+use serenity::framework::standard::{Args, CommandError, CommandResult};
 use serenity::model::channel::Message;
 
 use crate::commands::common::handle_autodelete;
 use crate::commands::music::{get_music_player_for_guild, DJ_CHECK};
 use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use crate::providers::music::queue::ShuffleMode;
 use serenity_additions::core::SHORT_TIMEOUT;
 use serenity_additions::ephemeral_message::EphemeralMessage;
 
 #[command]
 #[only_in(guilds)]
-#[description("Shuffles the queue")]
-#[usage("")]
+#[description(
+    "Shuffles the queue. The smart mode spreads songs of the same artist across the queue"
+)]
+#[usage("[random|smart]")]
+#[example("smart")]
+#[max_args(1)]
 #[aliases("sh")]
 #[bucket("general")]
 #[checks(DJ)]
-async fn shuffle(ctx: &Context, msg: &Message) -> CommandResult {
+async fn shuffle(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     let guild = msg.guild(&ctx.cache).unwrap();
+    let mode = if args.is_empty() {
+        ShuffleMode::Random
+    } else {
+        match args.single::<ShuffleMode>() {
+            Ok(ShuffleMode::Off) | Err(_) => {
+                msg.channel_id
+                    .say(ctx, "The shuffle mode needs to be `random` or `smart`")
+                    .await?;
+                return Ok(());
+            }
+            Ok(mode) => mode,
+        }
+    };
 
     tracing::debug!("Shuffling queue for guild {}", guild.id);
     let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
@@ -29,11 +47,12 @@ async fn shuffle(ctx: &Context, msg: &Message) -> CommandResult {
     };
     {
         let mut player = player.lock().await;
-        player.queue().shuffle();
+        player.queue().shuffle_with_mode(mode);
+        player.on_queue_changed().await?;
     }
 
     EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| {
-        m.content("🔀 The queue has been shuffled")
+        m.content(format!("🔀 The queue has been shuffled ({})", mode))
     })
     .await?;
     handle_autodelete(ctx, msg).await?;
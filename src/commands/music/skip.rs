@@ -4,18 +4,17 @@ use serenity::framework::standard::{CommandError, CommandResult};
 use serenity::model::channel::Message;
 
 use crate::commands::common::handle_autodelete;
-use crate::commands::music::{get_music_player_for_guild, DJ_CHECK};
+use crate::commands::music::{get_music_player_for_guild, skip_or_vote, SkipResult};
 use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
 use serenity_additions::core::SHORT_TIMEOUT;
 use serenity_additions::ephemeral_message::EphemeralMessage;
 
 #[command]
 #[only_in(guilds)]
-#[description("Skips to the next song")]
+#[description("Skips to the next song or votes to skip it if vote skipping is enabled")]
 #[usage("")]
 #[aliases("next")]
 #[bucket("general")]
-#[checks(DJ)]
 async fn skip(ctx: &Context, msg: &Message) -> CommandResult {
     let guild = msg.guild(&ctx.cache).unwrap();
     tracing::debug!("Skipping song for guild {}", guild.id);
@@ -27,15 +26,17 @@ async fn skip(ctx: &Context, msg: &Message) -> CommandResult {
             .await
             .map_err(CommandError::from);
     };
-    {
-        let mut player = player.lock().await;
-        player.skip().await?;
-    }
+    let content = match skip_or_vote(ctx, guild.id, &msg.author, player).await? {
+        SkipResult::Skipped => "⏭ Skipped to the next song".to_string(),
+        SkipResult::Voted { votes, required } => {
+            format!("🗳️ Voted to skip the song ({}/{})", votes, required)
+        }
+        SkipResult::NotListening => "You need to be listening to vote".to_string(),
+        SkipResult::NotAllowed => "Lacking DJ role".to_string(),
+    };
 
-    EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| {
-        m.content("⏭ Skipped to the next song")
-    })
-    .await?;
+    EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| m.content(content))
+        .await?;
     handle_autodelete(ctx, msg).await?;
 
     Ok(())
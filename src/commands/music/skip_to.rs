@@ -0,0 +1,50 @@
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::{get_music_player_for_guild, DJ_CHECK};
+use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandError, CommandResult};
+use serenity::model::channel::Message;
+use serenity_additions::core::SHORT_TIMEOUT;
+use serenity_additions::ephemeral_message::EphemeralMessage;
+
+#[command]
+#[description("Skips to the song at the given position of the queue dropping the songs before it")]
+#[usage("<pos>")]
+#[example("4")]
+#[num_args(1)]
+#[bucket("general")]
+#[only_in(guilds)]
+#[aliases("skipto", "skip-to", "st")]
+#[checks(DJ)]
+async fn skip_to(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+    tracing::debug!("Skipping to song for guild {}", guild.id);
+
+    let pos = args.single::<usize>()?;
+    let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
+        player
+    } else {
+        return create_no_voicechannel_message(&ctx.http, msg.channel_id)
+            .await
+            .map_err(CommandError::from);
+    };
+
+    let skipped = {
+        let mut player = player.lock().await;
+        let skipped = player.skip_to(pos).await?;
+        player.on_queue_changed().await?;
+        skipped
+    };
+    let content = if skipped {
+        format!("⏭ Skipped to the song at `{}`", pos)
+    } else {
+        format!("There's no song at `{}`", pos)
+    };
+
+    EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| m.content(content))
+        .await?;
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use serenity::client::Context;
+use serenity::framework::standard::macros::command;
+use serenity::framework::standard::{Args, CommandError, CommandResult};
+use serenity::model::channel::Message;
+
+use crate::commands::common::handle_autodelete;
+use crate::commands::music::{get_music_player_for_guild, DJ_CHECK};
+use crate::messages::music::no_voicechannel::create_no_voicechannel_message;
+use crate::providers::music::player::MAX_VOLUME;
+use serenity_additions::core::SHORT_TIMEOUT;
+use serenity_additions::ephemeral_message::EphemeralMessage;
+
+#[command]
+#[only_in(guilds)]
+#[description("Sets the playback volume in percent. Shows the current volume if no value is given")]
+#[usage("[<0-200>]")]
+#[example("80")]
+#[max_args(1)]
+#[aliases("vol")]
+#[bucket("general")]
+#[checks(DJ)]
+async fn volume(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).unwrap();
+
+    let player = if let Some(player) = get_music_player_for_guild(ctx, guild.id).await {
+        player
+    } else {
+        return create_no_voicechannel_message(&ctx.http, msg.channel_id)
+            .await
+            .map_err(CommandError::from);
+    };
+    let mut player = player.lock().await;
+
+    if args.is_empty() {
+        EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| {
+            m.content(format!("🔊 The volume is `{}%`", player.volume()))
+        })
+        .await?;
+    } else {
+        let volume = args.single::<u32>()?;
+
+        if volume > MAX_VOLUME {
+            msg.channel_id
+                .say(
+                    ctx,
+                    format!("‼️ The volume must be between 0 and {}", MAX_VOLUME),
+                )
+                .await?;
+            return Ok(());
+        }
+        tracing::debug!("Setting volume for guild {} to {}", guild.id, volume);
+        player.set_volume(volume)?;
+        player.update_now_playing().await?;
+
+        EphemeralMessage::create(&ctx.http, msg.channel_id, SHORT_TIMEOUT, |m| {
+            m.content(format!("🔊 Set the volume to `{}%`", volume))
+        })
+        .await?;
+    }
+    handle_autodelete(ctx, msg).await?;
+
+    Ok(())
+}
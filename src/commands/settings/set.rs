@@ -12,7 +12,7 @@ use crate::utils::context_data::get_database_from_context;
     "Set a guild setting. If no value is given the setting will be reset to the default value."
 )]
 #[usage("<setting> [<value>]")]
-#[example("music.autoshuffle true")]
+#[example("music.autoshuffle smart")]
 #[min_args(1)]
 #[max_args(2)]
 #[required_permissions("MANAGE_GUILD")]
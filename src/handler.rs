@@ -1,5 +1,6 @@
 use serenity::async_trait;
 use serenity::client::Context;
+use serenity::model::application::interaction::Interaction;
 use serenity::model::channel::GuildChannel;
 use serenity::model::event::ResumedEvent;
 use serenity::model::gateway::{Activity, Ready};
@@ -9,6 +10,8 @@ use serenity::model::voice::VoiceState;
 use serenity::prelude::*;
 
 use crate::commands::music::get_music_player_for_guild;
+use crate::messages::music::now_playing::handle_now_playing_interaction;
+use crate::providers::music::player::restore_music_players;
 use crate::utils::context_data::MusicPlayers;
 use crate::utils::delete_messages_from_database;
 use serenity::model::event;
@@ -30,9 +33,12 @@ pub fn get_raw_event_handler() -> RichEventHandler {
     handler
 }
 
-async fn ready(ctx: &Context, _: &Ready) -> Result<()> {
+async fn ready(ctx: &Context, ready: &Ready) -> Result<()> {
     tracing::info!("Ready");
     delete_messages_from_database(&ctx).await?;
+    if let Err(e) = restore_music_players(&ctx, ready).await {
+        tracing::error!("Failed to restore music players: {:?}", e);
+    }
     let prefix = std::env::var("BOT_PREFIX").unwrap_or("~!".to_string());
     ctx.set_activity(Activity::listening(format!("{prefix}help").as_str()))
         .await;
@@ -48,6 +54,14 @@ impl EventHandler for Handler {
         tracing::info!("Reconnected to gateway")
     }
 
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        if let Interaction::MessageComponent(component) = interaction {
+            if let Err(e) = handle_now_playing_interaction(&ctx, &component).await {
+                tracing::error!("Failed to handle component interaction: {:?}", e);
+            }
+        }
+    }
+
     async fn voice_state_update(
         &self,
         ctx: Context,
@@ -79,6 +93,18 @@ impl EventHandler for Handler {
                 player.set_leave_flag(count == 0);
             }
         }
+        // keep track of the channel when the bot gets moved
+        if let Some(channel_id) = new_state.channel_id {
+            if new_state.user_id == ctx.cache.current_user().id {
+                if let Some(player) = get_music_player_for_guild(&ctx, guild_id).await {
+                    let mut player = player.lock().await;
+
+                    if let Err(e) = player.set_voice_channel(channel_id).await {
+                        tracing::error!("Failed to update voice channel of player: {:?}", e);
+                    }
+                }
+            }
+        }
         // handle disconnects
         if let (Some(state), None) = (old_state, new_state.channel_id) {
             let current_user = ctx.cache.current_user();
@@ -98,7 +124,10 @@ impl EventHandler for Handler {
 }
 
 /// Returns the number of members in the channel if it's the bots voice channel
-async fn get_own_channel_member_count(ctx: &Context, channel_id: ChannelId) -> Option<usize> {
+pub(crate) async fn get_own_channel_member_count(
+    ctx: &Context,
+    channel_id: ChannelId,
+) -> Option<usize> {
     let guild_channel = get_guild_channel(ctx, channel_id).await?;
 
     let current_user = ctx.cache.current_user();
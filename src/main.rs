@@ -1,6 +1,9 @@
 use crate::client::get_client;
 use crate::utils::logging::init_logger;
 
+#[cfg(test)]
+mod tests;
+
 mod client;
 mod commands;
 mod handler;
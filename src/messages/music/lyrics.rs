@@ -0,0 +1,233 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use bot_coreutils::lrc::{line_at, LrcLine};
+use serenity::builder::{CreateEmbed, CreateMessage};
+use serenity::client::Context;
+use serenity::model::id::{ChannelId, GuildId};
+use serenity_additions::menu::{MenuBuilder, Page};
+use tokio::sync::Mutex;
+
+use crate::commands::music::get_music_player_for_guild;
+use crate::providers::music::lyrics::Lyrics;
+use crate::providers::music::player::MusicPlayer;
+use crate::providers::music::queue::Song;
+use crate::utils::error::BotResult;
+
+/// The maximum number of characters on a page of lyrics
+static LYRICS_PAGE_SIZE: usize = 2000;
+
+/// The interval in which the live lyrics are checked for a new line
+static LIVE_UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The time live lyrics are updated after the song should have ended
+/// to account for pauses and slower playback
+static LIVE_LYRICS_MARGIN: Duration = Duration::from_secs(120);
+
+/// The number of lines shown before and after the current line of live lyrics
+static LIVE_CONTEXT_LINES: usize = 3;
+
+/// Creates a message with the lyrics of a song.
+/// Long lyrics are split into the pages of a menu
+pub async fn create_lyrics_menu(
+    ctx: &Context,
+    channel_id: ChannelId,
+    song: &Song,
+    lyrics: Lyrics,
+) -> BotResult<()> {
+    let title = format!("Lyrics for {} by {}", song.title(), song.author());
+    let chunks = split_lyrics(&lyrics.plain, LYRICS_PAGE_SIZE);
+
+    if chunks.len() <= 1 {
+        channel_id
+            .send_message(ctx, |m| {
+                m.embed(|e| {
+                    e.title(title)
+                        .description(chunks.first().cloned().unwrap_or_default())
+                        .footer(|f| f.text(format!("Powered by {}", lyrics.provider)))
+                })
+            })
+            .await?;
+        return Ok(());
+    }
+    let page_count = chunks.len();
+    let pages: Vec<Page<'static>> = chunks
+        .into_iter()
+        .enumerate()
+        .map(|(i, chunk)| {
+            let mut message = CreateMessage::default();
+            message.embed(|e| {
+                e.title(&title).description(chunk).footer(|f| {
+                    f.text(format!(
+                        "Page {} of {} • Powered by {}",
+                        i + 1,
+                        page_count,
+                        lyrics.provider
+                    ))
+                })
+            });
+            Page::new_static(message)
+        })
+        .collect();
+
+    MenuBuilder::new_paginator()
+        .add_pages(pages)
+        .timeout(Duration::from_secs(300))
+        .build(ctx, channel_id)
+        .await?;
+
+    Ok(())
+}
+
+/// Creates a message that shows the current line of the synced lyrics
+/// and is updated until the song changes or playback stops
+pub async fn create_live_lyrics_msg(
+    ctx: &Context,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    player: Arc<Mutex<MusicPlayer>>,
+    song: Song,
+    lines: Vec<LrcLine>,
+    provider: String,
+) -> BotResult<()> {
+    let title = format!("Lyrics for {} by {}", song.title(), song.author());
+    let mut message = channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| create_live_lyrics_embed(e, &title, &lines, None, &provider))
+        })
+        .await?;
+    let ctx = ctx.clone();
+    let song_duration = song
+        .duration()
+        .or_else(|| lines.last().map(|l| l.time))
+        .unwrap_or_default();
+    let deadline = Instant::now() + song_duration + LIVE_LYRICS_MARGIN;
+
+    tokio::spawn(async move {
+        let mut last_line = None;
+
+        loop {
+            tokio::time::sleep(LIVE_UPDATE_INTERVAL).await;
+
+            if Instant::now() > deadline {
+                tracing::debug!("Live lyrics exceeded the duration of the song");
+                break;
+            }
+            let is_active_player = get_music_player_for_guild(&ctx, guild_id)
+                .await
+                .map(|p| Arc::ptr_eq(&p, &player))
+                .unwrap_or(false);
+
+            if !is_active_player {
+                tracing::debug!("Player was removed");
+                break;
+            }
+            let position = {
+                let mut player = player.lock().await;
+                let is_same_song = player
+                    .queue()
+                    .current()
+                    .as_ref()
+                    .map(|s| s.title() == song.title() && s.url == song.url)
+                    .unwrap_or(false);
+
+                if !is_same_song || !player.has_track() {
+                    break;
+                }
+                player.position().await
+            };
+            let line = position.and_then(|p| line_at(&lines, p));
+
+            if line == last_line {
+                continue;
+            }
+            last_line = line;
+
+            if let Err(e) = message
+                .edit(&ctx, |m| {
+                    m.embed(|e| create_live_lyrics_embed(e, &title, &lines, line, &provider))
+                })
+                .await
+            {
+                tracing::error!("Failed to update live lyrics: {:?}", e);
+                return;
+            }
+        }
+        tracing::debug!("Song changed or playback stopped. Stopping live lyrics");
+
+        if let Err(e) = message
+            .edit(&ctx, |m| {
+                m.embed(|e| {
+                    e.title(&title)
+                        .description("*The song has ended*")
+                        .footer(|f| f.text(format!("Powered by {}", provider)))
+                })
+            })
+            .await
+        {
+            tracing::error!("Failed to update live lyrics: {:?}", e);
+        }
+    });
+
+    Ok(())
+}
+
+/// Creates the embed showing the lines around the current line of the lyrics
+fn create_live_lyrics_embed<'a>(
+    embed: &'a mut CreateEmbed,
+    title: &str,
+    lines: &[LrcLine],
+    current: Option<usize>,
+    provider: &str,
+) -> &'a mut CreateEmbed {
+    let center = current.unwrap_or(0);
+    let start = center.saturating_sub(LIVE_CONTEXT_LINES);
+    let end = (center + LIVE_CONTEXT_LINES + 1).min(lines.len());
+    let description = lines[start..end]
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let text = if line.text.is_empty() {
+                "♪"
+            } else {
+                line.text.as_str()
+            };
+            if Some(start + i) == current {
+                format!("**▶ {}**", text)
+            } else {
+                text.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    embed
+        .title(title)
+        .description(description)
+        .footer(|f| f.text(format!("Powered by {}", provider)))
+}
+
+/// Splits the lyrics into chunks of at most the given size
+/// preferring to split between paragraphs and lines
+fn split_lyrics(lyrics: &str, max_size: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut chunk = String::new();
+
+    for line in lyrics.lines() {
+        let line: String = line.chars().take(max_size).collect();
+        let paragraph_end = line.trim().is_empty() && chunk.len() > max_size / 2;
+
+        if chunk.len() + line.len() + 1 > max_size || paragraph_end {
+            chunks.push(chunk.trim().to_string());
+            chunk = String::new();
+        }
+        chunk.push_str(&line);
+        chunk.push('\n');
+    }
+    if !chunk.trim().is_empty() {
+        chunks.push(chunk.trim().to_string());
+    }
+    chunks.retain(|c| !c.is_empty());
+
+    chunks
+}
@@ -1,3 +1,6 @@
+pub mod lyrics;
 pub mod no_voicechannel;
 pub mod now_playing;
+pub mod playlists;
 pub mod queue;
+pub mod search;
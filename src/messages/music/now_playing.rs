@@ -1,31 +1,65 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use serenity::builder::CreateEmbed;
+use serenity::builder::{CreateComponents, CreateEmbed};
 use serenity::http::Http;
+use serenity::model::application::component::ButtonStyle;
+use serenity::model::application::interaction::message_component::MessageComponentInteraction;
+use serenity::model::channel::ReactionType;
+use serenity::model::id::GuildId;
 use serenity::model::prelude::ChannelId;
 
-use crate::commands::music::{get_music_player_for_guild, get_voice_manager, is_dj};
+use crate::commands::music::{
+    get_music_player_for_guild, get_voice_manager, is_dj, skip_or_vote, SkipResult,
+};
 use crate::messages::add_ephemeral_handle_to_database;
 use crate::providers::music::add_youtube_song_to_database;
+use crate::providers::music::filters::AudioFilter;
 use crate::providers::music::player::MusicPlayer;
-use crate::providers::music::queue::Song;
-use crate::utils::context_data::{DatabaseContainer, MusicPlayers, Store};
+use crate::providers::music::playlists::add_favorite;
+use crate::providers::music::queue::{LoopMode, Song};
+use crate::utils::context_data::{
+    get_database_from_context, DatabaseContainer, MusicPlayers, Store,
+};
 use crate::utils::error::*;
+use bot_coreutils::time::format_duration;
 use serenity::builder::CreateMessage;
 use serenity::client::Context;
-use serenity::model::channel::Reaction;
+use serenity::model::user::User;
 use serenity_additions::core::MessageHandle;
-use serenity_additions::menu::{Menu, MenuBuilder, Page};
-use serenity_additions::Result as SerenityUtilsResult;
+use serenity_additions::menu::{MenuBuilder, Page};
 use std::env;
 use std::time::Duration;
 use tokio::sync::{Mutex, RwLock};
 
+/// The prefix of the custom ids of the now playing components
+static COMPONENT_PREFIX: &str = "np:";
+
 static DELETE_BUTTON: &str = "🗑️";
 static PAUSE_BUTTON: &str = "⏯️";
+static PREVIOUS_BUTTON: &str = "⏮️";
 static SKIP_BUTTON: &str = "⏭️";
 static STOP_BUTTON: &str = "⏹️";
 static GOOD_PICK_BUTTON: &str = "👍";
+static LIKE_BUTTON: &str = "❤️";
+
+/// The filters that can be selected in the now playing message
+static SELECTABLE_FILTERS: &[AudioFilter] = &[
+    AudioFilter::BassBoost,
+    AudioFilter::Nightcore,
+    AudioFilter::Vaporwave,
+    AudioFilter::EightD,
+    AudioFilter::Loudnorm,
+];
+
+/// The maximum number of options of a select menu
+static MAX_SELECT_OPTIONS: usize = 25;
+
+static LACKING_DJ_ROLE: &str = "You need the DJ role to do that";
+
+/// The service used to fetch the icon of the site a song is from
+static SITE_ICON_URL: &str = "https://icons.duckduckgo.com/ip3/";
 
 /// Creates a new now playing message and returns the embed for that message
 pub async fn create_now_playing_msg(
@@ -36,46 +70,22 @@ pub async fn create_now_playing_msg(
     tracing::debug!("Creating now playing menu");
     let nsfw = channel_id.to_channel(ctx).await?.is_nsfw();
     let handle = MenuBuilder::default()
-        .add_control(-1, DELETE_BUTTON, |c, m, r| {
-            Box::pin(delete_action(c, m, r))
-        })
-        .add_help(DELETE_BUTTON, "Deletes this message")
-        .add_control(0, STOP_BUTTON, |c, m, r| {
-            Box::pin(stop_button_action(c, m, r))
-        })
-        .add_help(STOP_BUTTON, "Stops the music and leaves the channel")
-        .add_control(1, PAUSE_BUTTON, |c, m, r| {
-            Box::pin(play_pause_button_action(c, m, r))
-        })
-        .add_help(PAUSE_BUTTON, "Pauses the music")
-        .add_control(2, SKIP_BUTTON, |c, m, r| {
-            Box::pin(skip_button_action(c, m, r))
-        })
-        .add_help(SKIP_BUTTON, "Skips to the next song")
-        .add_control(3, GOOD_PICK_BUTTON, |c, m, r| {
-            Box::pin(good_pick_action(c, m, r))
-        })
-        .add_help(
-            GOOD_PICK_BUTTON,
-            "Remembers this video for spotify-youtube mappings",
-        )
-        .show_help()
         .add_page(Page::new_builder(move || {
             let player = Arc::clone(&player);
             Box::pin(async move {
                 tracing::debug!("Creating now playing embed for page");
-                let mut player = player.lock().await;
+                let player = player.lock().await;
                 tracing::debug!("player locked");
                 let mut page = CreateMessage::default();
 
-                if let Some(mut current) = player.queue().current().clone() {
+                if let Some(mut info) = player.now_playing_info().await {
                     let mut embed = CreateEmbed::default();
-                    create_now_playing_embed(&mut current, &mut embed, player.is_paused(), nsfw)
-                        .await;
+                    create_now_playing_embed(&mut info, &mut embed, nsfw).await;
                     page.embed(|e| {
                         e.0.clone_from(&embed.0);
                         e
                     });
+                    page.components(|c| create_now_playing_components(c, &info));
                 } else {
                     page.embed(|e| e.description("Queue is empty"));
                 }
@@ -94,12 +104,24 @@ pub async fn create_now_playing_msg(
     Ok(handle)
 }
 
+/// The state of the player that is displayed in the now playing message
+pub struct NowPlayingInfo {
+    pub song: Song,
+    pub paused: bool,
+    pub loop_mode: LoopMode,
+    pub volume: u32,
+    pub filters: Vec<AudioFilter>,
+    pub skip_votes: Option<(usize, usize)>,
+    pub position: Option<Duration>,
+    pub duration: Option<Duration>,
+    pub upcoming: Vec<Song>,
+}
+
 /// Updates the now playing message with new content
 pub async fn update_now_playing_msg(
     http: &Arc<Http>,
     handle: &Arc<RwLock<MessageHandle>>,
-    song: &mut Song,
-    paused: bool,
+    info: &mut NowPlayingInfo,
 ) -> BotResult<()> {
     tracing::debug!("Updating now playing message");
     let handle = handle.read().await;
@@ -107,13 +129,14 @@ pub async fn update_now_playing_msg(
     let nsfw = http.get_channel(handle.channel_id).await?.is_nsfw();
 
     let mut embed = CreateEmbed::default();
-    create_now_playing_embed(song, &mut embed, paused, nsfw).await;
+    create_now_playing_embed(info, &mut embed, nsfw).await;
     message
         .edit(http, |m| {
             m.embed(|e| {
                 e.0.clone_from(&embed.0);
                 e
             })
+            .components(|c| create_now_playing_components(c, info))
         })
         .await?;
     tracing::debug!("Message updated.");
@@ -123,20 +146,44 @@ pub async fn update_now_playing_msg(
 
 /// Creates the embed of the now playing message
 async fn create_now_playing_embed<'a>(
-    song: &mut Song,
+    info: &mut NowPlayingInfo,
     mut embed: &'a mut CreateEmbed,
-    paused: bool,
     nsfw: bool,
 ) -> &'a mut CreateEmbed {
-    let url = song.url().await.unwrap();
+    let song = &mut info.song;
+    let url = song.url().await.unwrap_or_default();
     embed = embed
-        .title(if paused { "Paused" } else { "Playing" })
+        .title(match (info.paused, song.is_autoplay()) {
+            (true, _) => "Paused",
+            (false, true) => "Playing (Autoplay)",
+            (false, false) => "Playing",
+        })
         .description(format!(
-            "[{}]({}) by {}",
+            "[{}]({}) by {}\n{}",
             song.title().clone(),
             url,
-            song.author().clone()
+            song.author().clone(),
+            create_progress_bar(info.position, info.duration)
         ))
+        .field(
+            "Loop",
+            format!("{} {}", info.loop_mode.emoji(), info.loop_mode),
+            true,
+        )
+        .field("Volume", format!("🔊 {}%", info.volume), true)
+        .field(
+            "Filters",
+            if info.filters.is_empty() {
+                "none".to_string()
+            } else {
+                info.filters
+                    .iter()
+                    .map(|f| f.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            },
+            true,
+        )
         .footer(|f| {
             f.text(format!(
                 "Use {}play to add a song to the queue",
@@ -144,6 +191,25 @@ async fn create_now_playing_embed<'a>(
             ))
         });
 
+    if let Some((votes, required)) = info.skip_votes {
+        embed = embed.field("Skip votes", format!("⏭️ {}/{}", votes, required), true);
+    }
+    if let Some(site) = song.source().site_name() {
+        let site = site.to_string();
+        let icon = song
+            .source()
+            .site_domain()
+            .map(|domain| format!("{}{}.ico", SITE_ICON_URL, domain));
+
+        embed = embed.author(|a| {
+            a.name(site);
+            if let Some(icon) = icon {
+                a.icon_url(icon);
+            }
+            a
+        });
+    }
+
     if nsfw {
         if let Some(thumb) = song.thumbnail().clone() {
             embed = embed.thumbnail(thumb);
@@ -153,149 +219,396 @@ async fn create_now_playing_embed<'a>(
     embed
 }
 
-/// Toggled when the pause button is pressed
-async fn play_pause_button_action(
-    ctx: &Context,
-    _: &mut Menu<'_>,
-    reaction: Reaction,
-) -> SerenityUtilsResult<()> {
-    tracing::debug!("Play/Pause button pressed");
-    let guild_id = reaction.guild_id.unwrap();
-    let user = reaction.user(&ctx).await?;
-
-    if !is_dj(ctx, guild_id, &user).await? {
-        return Ok(());
+/// Creates a progress bar with the elapsed and total time of the track
+fn create_progress_bar(position: Option<Duration>, duration: Option<Duration>) -> String {
+    static BAR_LENGTH: usize = 20;
+    let position = position.unwrap_or_default();
+
+    if let Some(duration) = duration.filter(|d| !d.is_zero()) {
+        let progress = (position.as_secs_f64() / duration.as_secs_f64()).min(1.0);
+        let marker_index = (progress * (BAR_LENGTH - 1) as f64).round() as usize;
+        let bar: String = (0..BAR_LENGTH)
+            .map(|i| if i == marker_index { '🔘' } else { '▬' })
+            .collect();
+
+        format!(
+            "`{}` {} `{}`",
+            format_duration(position),
+            bar,
+            format_duration(duration)
+        )
+    } else {
+        format!("`{}`", format_duration(position))
     }
-    {
-        let player = get_music_player_for_guild(ctx, guild_id).await.unwrap();
+}
 
-        let (current, message, paused) = {
-            tracing::debug!("Queue is locked");
-            let mut player = player.lock().await;
-            player.toggle_paused().await?;
-            (
-                player.queue().current().clone(),
-                player.now_playing_message().clone().unwrap(),
-                player.is_paused(),
-            )
-        };
-        tracing::debug!("Queue is unlocked");
-
-        if let Some(mut current) = current {
-            update_now_playing_msg(&ctx.http, &message, &mut current, paused).await?;
-        }
+/// Creates the buttons and select menus of the now playing message
+fn create_now_playing_components<'a>(
+    components: &'a mut CreateComponents,
+    info: &NowPlayingInfo,
+) -> &'a mut CreateComponents {
+    components.create_action_row(|r| {
+        r.create_button(|b| {
+            b.custom_id(component_id("stop"))
+                .emoji(ReactionType::Unicode(STOP_BUTTON.to_string()))
+                .style(ButtonStyle::Danger)
+        })
+        .create_button(|b| {
+            b.custom_id(component_id("pause"))
+                .emoji(ReactionType::Unicode(PAUSE_BUTTON.to_string()))
+                .label(if info.paused { "Resume" } else { "Pause" })
+                .style(ButtonStyle::Primary)
+        })
+        .create_button(|b| {
+            b.custom_id(component_id("previous"))
+                .emoji(ReactionType::Unicode(PREVIOUS_BUTTON.to_string()))
+                .style(ButtonStyle::Secondary)
+        })
+        .create_button(|b| {
+            b.custom_id(component_id("skip"))
+                .emoji(ReactionType::Unicode(SKIP_BUTTON.to_string()))
+                .style(ButtonStyle::Secondary)
+        })
+        .create_button(|b| {
+            b.custom_id(component_id("delete"))
+                .emoji(ReactionType::Unicode(DELETE_BUTTON.to_string()))
+                .style(ButtonStyle::Secondary)
+        })
+    });
+    components.create_action_row(|r| {
+        r.create_button(|b| {
+            b.custom_id(component_id("like"))
+                .emoji(ReactionType::Unicode(LIKE_BUTTON.to_string()))
+                .label("Favorite")
+                .style(ButtonStyle::Secondary)
+        })
+        .create_button(|b| {
+            b.custom_id(component_id("good-pick"))
+                .emoji(ReactionType::Unicode(GOOD_PICK_BUTTON.to_string()))
+                .label("Good pick")
+                .style(ButtonStyle::Secondary)
+        })
+    });
+    components.create_action_row(|r| {
+        r.create_select_menu(|s| {
+            s.custom_id(component_id("loop"))
+                .placeholder("Loop mode")
+                .options(|o| {
+                    for mode in [LoopMode::Off, LoopMode::Track, LoopMode::Queue] {
+                        o.create_option(|opt| {
+                            opt.label(format!("Loop: {}", mode))
+                                .value(mode.to_string())
+                                .emoji(ReactionType::Unicode(mode.emoji().to_string()))
+                                .default_selection(mode == info.loop_mode)
+                        });
+                    }
+                    o
+                })
+        })
+    });
+    components.create_action_row(|r| {
+        r.create_select_menu(|s| {
+            s.custom_id(component_id("filters"))
+                .placeholder("Filters")
+                .min_values(0)
+                .max_values(SELECTABLE_FILTERS.len() as u64)
+                .options(|o| {
+                    for filter in SELECTABLE_FILTERS {
+                        o.create_option(|opt| {
+                            opt.label(filter.to_string())
+                                .value(filter.to_string())
+                                .default_selection(info.filters.contains(filter))
+                        });
+                    }
+                    o
+                })
+        })
+    });
+    if !info.upcoming.is_empty() {
+        components.create_action_row(|r| {
+            r.create_select_menu(|s| {
+                s.custom_id(component_id("jump"))
+                    .placeholder("Jump to a song in the queue")
+                    .options(|o| {
+                        for (i, song) in info.upcoming.iter().take(MAX_SELECT_OPTIONS).enumerate() {
+                            o.create_option(|opt| {
+                                opt.label(truncate_label(&format!("{}. {}", i + 1, song.title())))
+                                    .description(truncate_label(song.author()))
+                                    .value(format!("{}:{}", i, song_key(song)))
+                            });
+                        }
+                        o
+                    })
+            })
+        });
     }
 
-    Ok(())
+    components
 }
 
-/// Triggered when the skip button is pressed
-async fn skip_button_action(
-    ctx: &Context,
-    _: &mut Menu<'_>,
-    reaction: Reaction,
-) -> SerenityUtilsResult<()> {
-    let guild_id = reaction.guild_id.unwrap();
-    let user = reaction.user(&ctx).await?;
-
-    if !is_dj(ctx, guild_id, &user).await? {
-        return Ok(());
+/// Returns a short key identifying a song in the queue
+fn song_key(song: &Song) -> String {
+    let mut hasher = DefaultHasher::new();
+    song.url.hash(&mut hasher);
+    song.title().hash(&mut hasher);
+    song.author().hash(&mut hasher);
+
+    format!("{:x}", hasher.finish())
+}
+
+/// Returns the custom id for a component of the now playing message
+fn component_id(action: &str) -> String {
+    format!("{}{}", COMPONENT_PREFIX, action)
+}
+
+/// Truncates a text to the maximum length of a select menu label
+fn truncate_label(text: &str) -> String {
+    static MAX_LABEL_LENGTH: usize = 100;
+
+    if text.chars().count() > MAX_LABEL_LENGTH {
+        let truncated: String = text.chars().take(MAX_LABEL_LENGTH - 1).collect();
+        format!("{}…", truncated)
+    } else {
+        text.to_string()
     }
+}
 
-    {
-        let player = get_music_player_for_guild(ctx, guild_id).await.unwrap();
-        let mut player = player.lock().await;
-        player.skip().await?;
+/// Handles the interactions with the components of the now playing message.
+/// Users get an ephemeral reply when an action isn't allowed or needs feedback
+pub async fn handle_now_playing_interaction(
+    ctx: &Context,
+    interaction: &MessageComponentInteraction,
+) -> BotResult<()> {
+    let action = match interaction.data.custom_id.strip_prefix(COMPONENT_PREFIX) {
+        Some(action) => action,
+        None => return Ok(()),
+    };
+    let guild_id = match interaction.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Ok(()),
+    };
+    tracing::debug!("Now playing action '{}' triggered in {}", action, guild_id);
+    interaction.defer(&ctx.http).await?;
+
+    let reply = match get_music_player_for_guild(ctx, guild_id).await {
+        Some(player) => now_playing_action(ctx, interaction, guild_id, player, action).await?,
+        None => Some("Nothing is playing".to_string()),
+    };
+
+    if let Some(content) = reply {
+        interaction
+            .create_followup_message(&ctx.http, |f| f.ephemeral(true).content(content))
+            .await?;
     }
 
     Ok(())
 }
 
-/// Triggered when the stop button is pressed
-async fn stop_button_action(
+/// Executes the action of a now playing component and returns
+/// the reply for the user if there is one
+async fn now_playing_action(
     ctx: &Context,
-    menu: &mut Menu<'_>,
-    reaction: Reaction,
-) -> SerenityUtilsResult<()> {
-    let guild_id = reaction.guild_id.unwrap();
-    let user = reaction.user(&ctx).await?;
-
-    if !is_dj(ctx, guild_id, &user).await? {
-        return Ok(());
+    interaction: &MessageComponentInteraction,
+    guild_id: GuildId,
+    player: Arc<Mutex<MusicPlayer>>,
+    action: &str,
+) -> BotResult<Option<String>> {
+    let user = &interaction.user;
+
+    match action {
+        // open to everyone as skipping starts a vote for users
+        // without the dj role and favorites are personal
+        "skip" => skip_action(ctx, guild_id, user, player).await,
+        "like" => like_action(ctx, user, player).await,
+        "delete" => delete_action(ctx, interaction, player).await,
+        _ if !is_dj(ctx, guild_id, user).await? => Ok(Some(LACKING_DJ_ROLE.to_string())),
+        "good-pick" => good_pick_action(ctx, player).await,
+        "stop" => stop_action(ctx, interaction, guild_id, player).await,
+        "pause" => {
+            let mut player = player.lock().await;
+            player.toggle_paused().await?;
+            player.update_now_playing().await?;
+            Ok(None)
+        }
+        "previous" => {
+            let mut player = player.lock().await;
+
+            if player.previous().await? {
+                player.update_now_playing().await?;
+                Ok(None)
+            } else {
+                Ok(Some("There's no previous song".to_string()))
+            }
+        }
+        "loop" => {
+            let mode = match interaction.data.values.first().map(String::as_str) {
+                Some("track") => LoopMode::Track,
+                Some("queue") => LoopMode::Queue,
+                _ => LoopMode::Off,
+            };
+            tracing::debug!("Setting loop mode to {}", mode);
+            let mut player = player.lock().await;
+            player.queue().set_loop_mode(mode);
+            player.update_now_playing().await?;
+            Ok(None)
+        }
+        "filters" => filters_action(interaction, player).await,
+        "jump" => {
+            let (index, key) = interaction
+                .data
+                .values
+                .first()
+                .and_then(|v| v.split_once(':'))
+                .and_then(|(i, key)| Some((i.parse::<usize>().ok()?, key.to_string())))
+                .ok_or(BotError::from("Invalid queue position"))?;
+            let mut player = player.lock().await;
+            // the queue might have changed since the message was rendered
+            let entries = player.queue().entries();
+            let position = if entries.get(index).map(song_key).as_ref() == Some(&key) {
+                Some(index)
+            } else {
+                entries.iter().position(|s| song_key(s) == key)
+            };
+
+            match position {
+                Some(position) if player.jump_to(position).await? => Ok(None),
+                _ => Ok(Some("The song isn't in the queue anymore".to_string())),
+            }
+        }
+        _ => Ok(None),
     }
-    {
-        let manager = get_voice_manager(ctx).await;
+}
 
-        let handler = manager.get(guild_id);
+/// Skips the song or registers a vote to skip it
+async fn skip_action(
+    ctx: &Context,
+    guild_id: GuildId,
+    user: &User,
+    player: Arc<Mutex<MusicPlayer>>,
+) -> BotResult<Option<String>> {
+    let reply = match skip_or_vote(ctx, guild_id, user, player).await? {
+        SkipResult::Skipped => None,
+        SkipResult::Voted { votes, required } => Some(format!(
+            "⏭️ Voted to skip the song ({}/{})",
+            votes, required
+        )),
+        SkipResult::NotListening => Some("You need to be in the voice channel to vote".to_string()),
+        SkipResult::NotAllowed => Some(LACKING_DJ_ROLE.to_string()),
+    };
 
-        if let Some(handler) = handler {
-            let mut handler_lock = handler.lock().await;
-            let _ = handler_lock.leave().await;
-        }
+    Ok(reply)
+}
 
-        if manager.get(guild_id).is_some() {
-            manager.remove(guild_id).await.map_err(BotError::from)?;
-            let mut data = ctx.data.write().await;
-            let players = data.get_mut::<MusicPlayers>().unwrap();
+/// Applies the selected filters keeping a custom speed
+async fn filters_action(
+    interaction: &MessageComponentInteraction,
+    player: Arc<Mutex<MusicPlayer>>,
+) -> BotResult<Option<String>> {
+    let mut player = player.lock().await;
+    let mut filters: Vec<AudioFilter> = player
+        .now_playing_info()
+        .await
+        .map(|info| info.filters)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|f| matches!(f, AudioFilter::Speed(_)))
+        .collect();
+    filters.extend(
+        interaction
+            .data
+            .values
+            .iter()
+            .filter_map(|v| v.parse::<AudioFilter>().ok()),
+    );
+    tracing::debug!("Setting filters to {:?}", filters);
+    player.set_filters(filters).await?;
+    player.update_now_playing().await?;
+
+    Ok(None)
+}
 
-            if let Some(player) = players.remove(&guild_id.0) {
-                let mut player = player.lock().await;
-                player.stop().await?;
-            }
+/// Stops the music, leaves the channel and deletes the message
+async fn stop_action(
+    ctx: &Context,
+    interaction: &MessageComponentInteraction,
+    guild_id: GuildId,
+    player: Arc<Mutex<MusicPlayer>>,
+) -> BotResult<Option<String>> {
+    let manager = get_voice_manager(ctx).await;
 
-            tracing::debug!("Left the voice channel");
-        } else {
-            tracing::debug!("Not in a voice channel");
-        }
+    if let Some(handler) = manager.get(guild_id) {
+        let mut handler_lock = handler.lock().await;
+        let _ = handler_lock.leave().await;
+    }
+    if manager.get(guild_id).is_some() {
+        manager.remove(guild_id).await?;
     }
     {
-        let handle = &menu.message;
-        let handle = handle.read().await;
-        ctx.http
-            .delete_message(handle.channel_id, handle.message_id)
-            .await?;
+        let mut data = ctx.data.write().await;
+        let players = data.get_mut::<MusicPlayers>().unwrap();
+        players.remove(&guild_id.0);
+    }
+    {
+        let mut player = player.lock().await;
+        player.clear_now_playing();
+        player.stop().await?;
     }
+    tracing::debug!("Left the voice channel");
+    interaction.message.delete(&ctx.http).await?;
 
-    Ok(())
+    Ok(None)
 }
 
+/// Adds the current song to the favorites of the user
+async fn like_action(
+    ctx: &Context,
+    user: &User,
+    player: Arc<Mutex<MusicPlayer>>,
+) -> BotResult<Option<String>> {
+    let current = player.lock().await.queue().current().clone();
+    let song = match current {
+        Some(song) => song,
+        None => return Ok(Some("Nothing is playing".to_string())),
+    };
+    let database = get_database_from_context(ctx).await;
+    let title = song.title().clone();
+
+    if add_favorite(&database, user.id, song).await? {
+        Ok(Some(format!("❤️ Added **{}** to your favorites", title)))
+    } else {
+        Ok(Some(format!(
+            "**{}** already is one of your favorites",
+            title
+        )))
+    }
+}
+
+/// Remembers the video of the current song for spotify-youtube mappings
 async fn good_pick_action(
     ctx: &Context,
-    _menu: &mut Menu<'_>,
-    reaction: Reaction,
-) -> SerenityUtilsResult<()> {
-    let guild_id = reaction.guild_id.unwrap();
-    let player = get_music_player_for_guild(ctx, guild_id).await.unwrap();
-    let mut player = player.lock().await;
+    player: Arc<Mutex<MusicPlayer>>,
+) -> BotResult<Option<String>> {
+    let current = player.lock().await.queue().current().clone();
 
-    if let Some(song) = player.queue().current() {
+    if let Some(mut song) = current {
         let data = ctx.data.read().await;
         let store = data.get::<Store>().unwrap();
         let database = data.get::<DatabaseContainer>().unwrap();
-        add_youtube_song_to_database(store, database, &mut song.clone()).await?;
+        add_youtube_song_to_database(store, database, &mut song).await?;
+        Ok(Some("👍 Remembered the video for this song".to_string()))
+    } else {
+        Ok(Some("Nothing is playing".to_string()))
     }
-
-    Ok(())
 }
 
+/// Deletes the now playing message
 async fn delete_action(
     ctx: &Context,
-    menu: &mut Menu<'_>,
-    reaction: Reaction,
-) -> SerenityUtilsResult<()> {
-    let guild_id = reaction.guild_id.unwrap();
-    let handle = {
-        let handle = menu.message.read().await;
-        handle.clone()
-    };
-    {
-        let player = get_music_player_for_guild(ctx, guild_id).await.unwrap();
-        let mut player = player.lock().await;
-        player.clear_now_playing();
-    }
-    ctx.http
-        .delete_message(handle.channel_id, handle.message_id)
-        .await?;
+    interaction: &MessageComponentInteraction,
+    player: Arc<Mutex<MusicPlayer>>,
+) -> BotResult<Option<String>> {
+    player.lock().await.clear_now_playing();
+    interaction.message.delete(&ctx.http).await?;
 
-    Ok(())
+    Ok(None)
 }
@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use serenity::builder::CreateMessage;
+use serenity::client::Context;
+use serenity::model::id::ChannelId;
+use serenity_additions::menu::{MenuBuilder, Page};
+
+use crate::utils::error::BotResult;
+
+static PLAYLISTS_PER_PAGE: usize = 15;
+
+/// Creates a paginated menu listing the saved playlists of a guild
+pub async fn create_playlists_menu(
+    ctx: &Context,
+    channel_id: ChannelId,
+    entries: Vec<String>,
+) -> BotResult<()> {
+    if entries.is_empty() {
+        channel_id.say(ctx, "There are no saved playlists").await?;
+        return Ok(());
+    }
+    let page_count = (entries.len() as f32 / PLAYLISTS_PER_PAGE as f32).ceil() as usize;
+    let pages: Vec<Page<'static>> = entries
+        .chunks(PLAYLISTS_PER_PAGE)
+        .enumerate()
+        .map(|(i, entries)| {
+            let mut message = CreateMessage::default();
+            message.embed(|e| {
+                e.title("Saved Playlists")
+                    .description(entries.join("\n"))
+                    .footer(|f| f.text(format!("Page {} of {}", i + 1, page_count)))
+            });
+            Page::new_static(message)
+        })
+        .collect();
+
+    MenuBuilder::new_paginator()
+        .add_pages(pages)
+        .timeout(Duration::from_secs(120))
+        .build(ctx, channel_id)
+        .await?;
+
+    Ok(())
+}
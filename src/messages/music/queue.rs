@@ -1,22 +1,127 @@
 use crate::providers::music::queue::Song;
 use crate::utils::error::BotResult;
+use bot_coreutils::time::format_duration;
 use serenity::builder::CreateMessage;
 use serenity::client::Context;
-use serenity::model::id::ChannelId;
+use serenity::model::id::{ChannelId, UserId};
 use serenity_additions::menu::{MenuBuilder, Page};
+use std::collections::HashMap;
 use std::time::Duration;
 
-/// Creates a new queue menu
+/// Creates a new queue menu showing the duration, requester,
+/// time of the request and estimated start time of every song
 pub async fn create_queue_menu(
     ctx: &Context,
     channel_id: ChannelId,
+    songs: Vec<(usize, Song, Option<Duration>)>,
+    fair: bool,
+    remaining: Option<Duration>,
+) -> BotResult<()> {
+    let title = if fair { "Queue (fair)" } else { "Queue" };
+    let requesters = songs.iter().filter_map(|(_, s, _)| s.requester());
+    let names = get_requester_names(ctx, requesters).await;
+    let entries = songs
+        .into_iter()
+        .map(|(i, s, start)| {
+            let mut details = vec![s
+                .duration()
+                .map(format_duration)
+                .unwrap_or_else(|| "?:??".to_string())];
+
+            if let Some(name) = s.requester().and_then(|r| names.get(&r)) {
+                details.push(format!("by {}", name));
+            }
+            if let Some(added) = s.added_at().and_then(|t| t.elapsed().ok()) {
+                details.push(format!("added {}", format_age(added)));
+            }
+            details.push(match start {
+                Some(start) if start.is_zero() => "next".to_string(),
+                Some(start) => format!("in {}", format_duration(start)),
+                None => "in ?".to_string(),
+            });
+            format!("{}\n     [{}]", format_song(i, &s), details.join(" • "))
+        })
+        .collect();
+    let remaining = format!(
+        "{} remaining",
+        remaining
+            .map(format_duration)
+            .unwrap_or_else(|| "unknown time".to_string())
+    );
+
+    create_entries_menu(ctx, channel_id, title, entries, Some(remaining)).await
+}
+
+/// Creates a new menu with the recently played songs
+pub async fn create_history_menu(
+    ctx: &Context,
+    channel_id: ChannelId,
+    songs: Vec<(usize, Song)>,
+) -> BotResult<()> {
+    create_songs_menu(ctx, channel_id, "History", songs).await
+}
+
+/// Creates a new menu with the songs of a saved playlist
+pub async fn create_playlist_menu(
+    ctx: &Context,
+    channel_id: ChannelId,
+    name: &str,
+    songs: Vec<(usize, Song)>,
+) -> BotResult<()> {
+    if songs.is_empty() {
+        channel_id
+            .say(ctx, format!("The playlist **{}** is empty", name))
+            .await?;
+        return Ok(());
+    }
+    create_songs_menu(ctx, channel_id, &format!("Playlist {}", name), songs).await
+}
+
+/// Creates a new menu with the favorite songs of a user
+pub async fn create_favorites_menu(
+    ctx: &Context,
+    channel_id: ChannelId,
+    songs: Vec<(usize, Song)>,
+) -> BotResult<()> {
+    if songs.is_empty() {
+        channel_id
+            .say(
+                ctx,
+                "You have no favorites yet. Use `like` to add the current song",
+            )
+            .await?;
+        return Ok(());
+    }
+    create_songs_menu(ctx, channel_id, "Favorites", songs).await
+}
+
+/// Creates a paginated menu listing the given songs
+async fn create_songs_menu(
+    ctx: &Context,
+    channel_id: ChannelId,
+    title: &str,
     songs: Vec<(usize, Song)>,
 ) -> BotResult<()> {
-    let page_count = (songs.len() as f32 / 10.0).ceil() as usize;
-    let pages: Vec<Page<'static>> = songs
+    let entries = songs.iter().map(|(i, s)| format_song(*i, s)).collect();
+
+    create_entries_menu(ctx, channel_id, title, entries, None).await
+}
+
+/// Creates a paginated menu listing the given formatted entries
+async fn create_entries_menu(
+    ctx: &Context,
+    channel_id: ChannelId,
+    title: &str,
+    entries: Vec<String>,
+    footer: Option<String>,
+) -> BotResult<()> {
+    let page_count = (entries.len() as f32 / 10.0).ceil() as usize;
+    let pages: Vec<Page<'static>> = entries
         .chunks(10)
         .enumerate()
-        .map(|(i, entries)| create_songs_page(page_count, i + 1, entries.to_vec()))
+        .map(|(i, entries)| {
+            create_songs_page(title, page_count, i + 1, entries, footer.as_deref())
+        })
         .collect();
 
     MenuBuilder::new_paginator()
@@ -29,17 +134,71 @@ pub async fn create_queue_menu(
 }
 
 /// Creates a new page with songs
-fn create_songs_page(total_pages: usize, page: usize, songs: Vec<(usize, Song)>) -> Page<'static> {
+fn create_songs_page(
+    title: &str,
+    total_pages: usize,
+    page: usize,
+    entries: &[String],
+    footer: Option<&str>,
+) -> Page<'static> {
     let mut message = CreateMessage::default();
-    let description_entries: Vec<String> = songs
-        .into_iter()
-        .map(|(i, s)| format!("{:0>3}. {} - {}", i, s.author(), s.title()))
-        .collect();
+    let footer = match footer {
+        Some(footer) => format!("Page {} of {} • {}", page, total_pages, footer),
+        None => format!("Page {} of {}", page, total_pages),
+    };
     message.embed(|e| {
-        e.title("Queue")
-            .description(format!("```md\n{}\n```", description_entries.join("\n")))
-            .footer(|f| f.text(format!("Page {} of {}", page, total_pages)))
+        e.title(title)
+            .description(format!("```md\n{}\n```", entries.join("\n")))
+            .footer(|f| f.text(footer))
     });
 
     Page::new_static(message)
 }
+
+/// Formats a song as an entry of a song list
+fn format_song(index: usize, song: &Song) -> String {
+    format!(
+        "{:0>3}. {} - {}{}",
+        index,
+        song.author(),
+        song.title(),
+        if song.is_autoplay() {
+            " (autoplay)"
+        } else {
+            ""
+        }
+    )
+}
+
+/// Formats the time since a song was added in the largest fitting unit
+fn format_age(age: Duration) -> String {
+    match age.as_secs() {
+        0..=59 => "just now".to_string(),
+        s @ 60..=3599 => format!("{}m ago", s / 60),
+        s @ 3600..=86399 => format!("{}h ago", s / 3600),
+        s => format!("{}d ago", s / 86400),
+    }
+}
+
+/// Returns the names of the users that requested songs
+async fn get_requester_names<I: IntoIterator<Item = UserId>>(
+    ctx: &Context,
+    user_ids: I,
+) -> HashMap<UserId, String> {
+    let mut names = HashMap::new();
+
+    for user_id in user_ids {
+        if names.contains_key(&user_id) {
+            continue;
+        }
+        let name = match ctx.cache.user(user_id) {
+            Some(user) => Some(user.name),
+            None => user_id.to_user(ctx).await.ok().map(|u| u.name),
+        };
+        if let Some(name) = name {
+            names.insert(user_id, name);
+        }
+    }
+
+    names
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use bot_coreutils::time::format_duration;
+use serenity::builder::CreateMessage;
+use serenity::client::Context;
+use serenity::model::channel::{Message, Reaction};
+use serenity_additions::menu::{close_menu, Menu, MenuBuilder, Page, CLOSE_MENU_EMOJI};
+use serenity_additions::Result as SerenityUtilsResult;
+
+use crate::commands::music::{enqueue_songs, get_or_join_music_player};
+use crate::providers::music::queue::Song;
+use crate::utils::error::BotResult;
+
+static RESULT_EMOJIS: &[&str] = &["1️⃣", "2️⃣", "3️⃣", "4️⃣", "5️⃣"];
+
+/// The number of search results that can be picked from
+pub static SEARCH_RESULT_COUNT: usize = 5;
+
+/// Creates a menu to pick one of the search results which is then added to the queue
+pub async fn create_search_menu(ctx: &Context, msg: &Message, songs: Vec<Song>) -> BotResult<()> {
+    let songs = Arc::new(songs);
+    let mut builder = MenuBuilder::default();
+
+    for (i, emoji) in RESULT_EMOJIS.iter().enumerate().take(songs.len()) {
+        let songs = Arc::clone(&songs);
+        let msg = msg.clone();
+
+        builder = builder.add_control(i as isize, *emoji, move |c, m, r| {
+            let song = songs[i].clone();
+            let msg = msg.clone();
+            Box::pin(async move { pick_result(c, m, r, &msg, song).await })
+        });
+    }
+    builder
+        .add_control(
+            RESULT_EMOJIS.len() as isize,
+            CLOSE_MENU_EMOJI,
+            |c, m, r| Box::pin(close_menu(c, m, r)),
+        )
+        .add_page(create_search_page(&songs))
+        .owner(msg.author.id)
+        .timeout(Duration::from_secs(60))
+        .build(ctx, msg.channel_id)
+        .await?;
+
+    Ok(())
+}
+
+/// Creates the page listing the search results
+fn create_search_page(songs: &[Song]) -> Page<'static> {
+    let mut message = CreateMessage::default();
+    let entries: Vec<String> = songs
+        .iter()
+        .zip(RESULT_EMOJIS)
+        .map(|(song, emoji)| {
+            format!(
+                "{} **{}** by {} ({})",
+                emoji,
+                song.title(),
+                song.author(),
+                song.duration()
+                    .map(format_duration)
+                    .unwrap_or_else(|| "?".to_string())
+            )
+        })
+        .collect();
+    message.embed(|e| {
+        e.title("Search results")
+            .description(entries.join("\n"))
+            .footer(|f| f.text("React with the number of the song to add it to the queue"))
+    });
+
+    Page::new_static(message)
+}
+
+/// Adds the picked song to the queue and closes the menu
+async fn pick_result(
+    ctx: &Context,
+    menu: &mut Menu<'_>,
+    reaction: Reaction,
+    msg: &Message,
+    song: Song,
+) -> SerenityUtilsResult<()> {
+    tracing::debug!("Picked search result {:?}", song);
+    let player = get_or_join_music_player(ctx, msg).await?;
+    enqueue_songs(ctx, msg, player, vec![song]).await?;
+    close_menu(ctx, menu, reaction).await?;
+
+    Ok(())
+}
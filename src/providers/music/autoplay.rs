@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use bot_database::Database;
+use rand::seq::SliceRandom;
+use regex::Regex;
+
+use crate::providers::music::queue::{Song, SongSource};
+use crate::providers::music::spotify::SpotifyApi;
+use crate::utils::error::BotResult;
+
+/// The number of recently played songs that are used to find related songs
+static RECENT_SONG_COUNT: usize = 5;
+
+/// Returns a song that is related to the recently played songs.
+/// Songs stored in the database are preferred over spotify recommendations.
+pub async fn find_autoplay_song(
+    database: &Database,
+    spotify: &SpotifyApi,
+    history: Vec<Song>,
+) -> BotResult<Option<Song>> {
+    let recent: Vec<&Song> = history.iter().rev().take(RECENT_SONG_COUNT).collect();
+    let played_urls: HashSet<&String> = history.iter().filter_map(|s| s.url.as_ref()).collect();
+    let artists: Vec<String> = recent
+        .iter()
+        .flat_map(|s| song_artists(s))
+        .collect::<HashSet<String>>()
+        .into_iter()
+        .collect();
+    tracing::debug!("Searching autoplay song for artists {:?}", artists);
+
+    if artists.is_empty() {
+        return Ok(None);
+    }
+    let candidates: Vec<_> = database
+        .get_songs_by_artists(artists.clone(), 50)
+        .await?
+        .into_iter()
+        .filter(|s| !played_urls.contains(&s.url))
+        .collect();
+    let stored_song = candidates
+        .choose_weighted(&mut rand::thread_rng(), |s| s.score.max(0) + 1)
+        .ok()
+        .cloned();
+
+    let mut song = if let Some(stored_song) = stored_song {
+        tracing::debug!("Using stored song {:?} for autoplay", stored_song);
+        Some(Song::from(stored_song))
+    } else {
+        find_related_spotify_song(spotify, &artists, &history).await?
+    };
+    if let Some(song) = &mut song {
+        song.autoplay = true;
+    }
+
+    Ok(song)
+}
+
+/// Searches for a song of an artist that is related to one of the given artists
+async fn find_related_spotify_song(
+    spotify: &SpotifyApi,
+    artists: &[String],
+    history: &[Song],
+) -> BotResult<Option<Song>> {
+    let artist_name = if let Some(name) = artists.choose(&mut rand::thread_rng()) {
+        name
+    } else {
+        return Ok(None);
+    };
+    let artist = if let Some(artist) = spotify.search_for_artist(artist_name).await? {
+        artist
+    } else {
+        return Ok(None);
+    };
+    let related = spotify.get_related_artists(&artist.id).await?;
+    let related_name = if let Some(related) = related.choose(&mut rand::thread_rng()) {
+        related.name.clone()
+    } else {
+        return Ok(None);
+    };
+    let tracks: Vec<_> = spotify
+        .search_artist_tracks(&related_name, 10)
+        .await?
+        .into_iter()
+        .filter(|t| !history.iter().any(|s| s.title() == &t.name))
+        .collect();
+    tracing::debug!("Found {} tracks of related artist '{}'", tracks.len(), related_name);
+
+    Ok(tracks
+        .choose(&mut rand::thread_rng())
+        .cloned()
+        .map(Song::from))
+}
+
+/// Returns the names of the artists of a song
+fn song_artists(song: &Song) -> Vec<String> {
+    lazy_static::lazy_static! {
+        static ref CHANNEL_AFFIXES: Regex = Regex::new(r"(?i)\s*-\s*topic$|vevo$|\s*official$").unwrap();
+    }
+    match song.source() {
+        SongSource::Spotify(track) => track.artists.iter().map(|a| a.name.clone()).collect(),
+        SongSource::SpotifyEpisode(_)
+        | SongSource::YouTube(_)
+        | SongSource::Direct(_)
+        | SongSource::Extractor { .. } => {
+            let name = CHANNEL_AFFIXES.replace_all(song.author(), "").trim().to_string();
+
+            if name.is_empty() {
+                Vec::new()
+            } else {
+                vec![name]
+            }
+        }
+    }
+}
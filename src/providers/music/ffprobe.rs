@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use bot_coreutils::process::run_command_async;
+use bot_coreutils::url::parse_url;
+use serde_json::Value;
+
+use crate::providers::music::queue::{Song, SongSource};
+use crate::utils::error::BotResult;
+
+/// Metadata of an audio file
+#[derive(Clone, Debug, Default)]
+pub struct AudioMetadata {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub duration: Option<Duration>,
+}
+
+/// Probes the audio file at the given url for its metadata
+pub async fn probe_audio(url: &str) -> BotResult<AudioMetadata> {
+    tracing::debug!("Probing audio file {}", url);
+    let output = run_command_async(
+        "ffprobe",
+        &["-v", "quiet", "-print_format", "json", "-show_format", url],
+    )
+    .await?;
+    let information: Value = serde_json::from_str(&output)?;
+    let format = &information["format"];
+    let tag = |name: &str| -> Option<String> {
+        format["tags"].as_object().and_then(|tags| {
+            tags.iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                .and_then(|(_, v)| v.as_str())
+                .map(String::from)
+        })
+    };
+    let duration = format["duration"]
+        .as_str()
+        .and_then(|d| d.parse::<f64>().ok())
+        .map(Duration::from_secs_f64);
+
+    Ok(AudioMetadata {
+        title: tag("title"),
+        artist: tag("artist"),
+        duration,
+    })
+}
+
+/// Creates a song for an audio file that is played directly from its url.
+/// The file name and given author are used when the file has no metadata
+pub async fn get_direct_song(url: &str, author: String) -> Song {
+    let metadata = probe_audio(url).await.unwrap_or_else(|e| {
+        tracing::error!("Failed to probe audio file: {:?}", e);
+        AudioMetadata::default()
+    });
+    let file_name = parse_url(url)
+        .ok()
+        .and_then(|u| u.path_segments()?.last().map(String::from))
+        .unwrap_or_else(|| url.to_string());
+
+    Song {
+        url: Some(url.to_string()),
+        title: metadata.title.unwrap_or(file_name),
+        author: metadata.artist.unwrap_or(author),
+        thumbnail: None,
+        source: SongSource::Direct(url.to_string()),
+        autoplay: false,
+        requester: None,
+        added_at: None,
+        duration: metadata.duration,
+    }
+}
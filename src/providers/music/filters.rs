@@ -0,0 +1,98 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// An audio filter preset that is applied with ffmpeg
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AudioFilter {
+    BassBoost,
+    Nightcore,
+    Vaporwave,
+    EightD,
+    Loudnorm,
+    Speed(f32),
+}
+
+impl AudioFilter {
+    /// Returns the ffmpeg filter expression for the preset
+    pub fn ffmpeg_filter(&self) -> String {
+        match self {
+            Self::BassBoost => "bass=g=10".to_string(),
+            Self::Nightcore => "aresample=48000,asetrate=48000*1.25,aresample=48000".to_string(),
+            Self::Vaporwave => "aresample=48000,asetrate=48000*0.8,aresample=48000".to_string(),
+            Self::EightD => "apulsator=hz=0.08".to_string(),
+            Self::Loudnorm => "loudnorm=I=-16:TP=-1.5:LRA=11".to_string(),
+            Self::Speed(speed) => format!("atempo={}", speed),
+        }
+    }
+
+    /// Returns the factor by which the preset speeds up playback
+    pub fn tempo(&self) -> f64 {
+        match self {
+            Self::Nightcore => 1.25,
+            Self::Vaporwave => 0.8,
+            Self::Speed(speed) => *speed as f64,
+            _ => 1.0,
+        }
+    }
+}
+
+impl Display for AudioFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BassBoost => write!(f, "bassboost"),
+            Self::Nightcore => write!(f, "nightcore"),
+            Self::Vaporwave => write!(f, "vaporwave"),
+            Self::EightD => write!(f, "8d"),
+            Self::Loudnorm => write!(f, "loudnorm"),
+            Self::Speed(speed) => write!(f, "speed={}", speed),
+        }
+    }
+}
+
+impl FromStr for AudioFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.to_lowercase();
+
+        if let Some(speed) = s.strip_prefix("speed=") {
+            let speed = speed
+                .parse::<f32>()
+                .map_err(|_| format!("Invalid speed '{}'", speed))?;
+
+            return if (0.5..=2.0).contains(&speed) {
+                Ok(Self::Speed(speed))
+            } else {
+                Err("The speed must be between 0.5 and 2".to_string())
+            };
+        }
+        match s.as_str() {
+            "bassboost" | "bass" => Ok(Self::BassBoost),
+            "nightcore" | "nc" => Ok(Self::Nightcore),
+            "vaporwave" | "vw" => Ok(Self::Vaporwave),
+            "8d" => Ok(Self::EightD),
+            "loudnorm" | "normalize" => Ok(Self::Loudnorm),
+            _ => Err(format!("Unknown filter '{}'", s)),
+        }
+    }
+}
+
+/// Joins the given filters to a single ffmpeg filter chain
+pub fn filter_chain(filters: &[AudioFilter]) -> Option<String> {
+    if filters.is_empty() {
+        None
+    } else {
+        Some(
+            filters
+                .iter()
+                .map(AudioFilter::ffmpeg_filter)
+                .collect::<Vec<String>>()
+                .join(","),
+        )
+    }
+}
+
+/// Returns the factor by which the given filters speed up playback
+pub fn tempo(filters: &[AudioFilter]) -> f64 {
+    filters.iter().map(AudioFilter::tempo).product()
+}
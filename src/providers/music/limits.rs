@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
+
+use bot_coreutils::time::{format_duration, parse_duration};
+use serenity::client::Context;
+use serenity::model::id::{GuildId, UserId};
+
+use crate::providers::music::queue::{MusicQueue, Song};
+use crate::providers::settings::{get_setting, Setting};
+use crate::utils::error::BotResult;
+
+/// Limits for songs added to the queue of a guild
+#[derive(Clone, Debug)]
+pub struct QueueLimits {
+    max_queue_length: Option<usize>,
+    max_user_songs: Option<usize>,
+    max_duration: Option<Duration>,
+    allow_playlists: bool,
+}
+
+/// The reason why a song was not added to the queue
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Rejection {
+    QueueFull(usize),
+    UserQuota(usize),
+    TooLong(Duration),
+    PlaylistsDisabled,
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::QueueFull(max) => write!(f, "the queue is full (max {} songs)", max),
+            Self::UserQuota(max) => write!(f, "you already queued the maximum of {} songs", max),
+            Self::TooLong(max) => write!(f, "longer than {}", format_duration(*max)),
+            Self::PlaylistsDisabled => write!(f, "playlists are disabled"),
+        }
+    }
+}
+
+impl QueueLimits {
+    /// Loads the queue limits configured for the given guild
+    pub async fn for_guild(ctx: &Context, guild_id: GuildId) -> BotResult<Self> {
+        let max_duration = get_setting::<String>(ctx, guild_id, Setting::MusicMaxDuration)
+            .await?
+            .and_then(|d| parse_duration(&d));
+
+        Ok(Self {
+            max_queue_length: get_setting(ctx, guild_id, Setting::MusicMaxQueueLength).await?,
+            max_user_songs: get_setting(ctx, guild_id, Setting::MusicMaxUserSongs).await?,
+            max_duration,
+            allow_playlists: get_setting(ctx, guild_id, Setting::MusicAllowPlaylists)
+                .await?
+                .unwrap_or(true),
+        })
+    }
+
+    /// Returns if the duration of songs is limited.
+    /// Unknown durations need to be fetched before applying the limits
+    /// as songs with an unknown duration are accepted
+    pub fn limits_duration(&self) -> bool {
+        self.max_duration.is_some()
+    }
+
+    /// Returns if the given number of songs may be added at once
+    pub fn allows_count(&self, count: usize) -> bool {
+        self.allow_playlists || count <= 1
+    }
+
+    /// Returns the number of songs the user can still add to the queue
+    /// or none if the number of songs isn't limited
+    pub fn remaining_capacity(&self, queue: &MusicQueue, user_id: UserId) -> Option<usize> {
+        let queue_capacity = self
+            .max_queue_length
+            .map(|max| max.saturating_sub(queue.entries().len()));
+        let user_capacity = self.max_user_songs.map(|max| {
+            let user_songs = queue
+                .entries()
+                .iter()
+                .filter(|s| s.requester() == Some(user_id))
+                .count();
+            max.saturating_sub(user_songs)
+        });
+
+        match (queue_capacity, user_capacity) {
+            (Some(queue_capacity), Some(user_capacity)) => Some(queue_capacity.min(user_capacity)),
+            (queue_capacity, user_capacity) => queue_capacity.or(user_capacity),
+        }
+    }
+
+    /// Returns if the song is known to be longer than the maximum duration
+    pub fn exceeds_duration(&self, song: &Song) -> bool {
+        match (self.max_duration, song.duration()) {
+            (Some(max), Some(duration)) => duration > max,
+            _ => false,
+        }
+    }
+
+    /// Splits the songs into the ones that can be added to the queue
+    /// and the ones that are rejected with the reason for the rejection
+    pub fn apply(
+        &self,
+        queue: &MusicQueue,
+        user_id: UserId,
+        songs: Vec<Song>,
+    ) -> (Vec<Song>, Vec<(Song, Rejection)>) {
+        if !self.allows_count(songs.len()) {
+            let rejected = songs
+                .into_iter()
+                .map(|s| (s, Rejection::PlaylistsDisabled))
+                .collect();
+            return (Vec::new(), rejected);
+        }
+        let mut queue_length = queue.entries().len();
+        let mut user_songs = queue
+            .entries()
+            .iter()
+            .filter(|s| s.requester() == Some(user_id))
+            .count();
+        let mut accepted = Vec::new();
+        let mut rejected = Vec::new();
+
+        for song in songs {
+            if let Some(max) = self.max_duration {
+                if song.duration().map(|d| d > max) == Some(true) {
+                    rejected.push((song, Rejection::TooLong(max)));
+                    continue;
+                }
+            }
+            if let Some(max) = self.max_queue_length {
+                if queue_length >= max {
+                    rejected.push((song, Rejection::QueueFull(max)));
+                    continue;
+                }
+            }
+            if let Some(max) = self.max_user_songs {
+                if user_songs >= max {
+                    rejected.push((song, Rejection::UserQuota(max)));
+                    continue;
+                }
+            }
+            queue_length += 1;
+            user_songs += 1;
+            accepted.push(song);
+        }
+
+        (accepted, rejected)
+    }
+}
+
+/// Creates a summary of rejected songs grouped by the reason
+pub fn rejection_summary(rejected: &[(Song, Rejection)]) -> String {
+    let mut reasons: Vec<(&Rejection, usize)> = Vec::new();
+    let mut counts: HashMap<&Rejection, usize> = HashMap::new();
+
+    for (_, reason) in rejected {
+        *counts.entry(reason).or_insert(0) += 1;
+    }
+    for (_, reason) in rejected {
+        if let Some(count) = counts.remove(reason) {
+            reasons.push((reason, count));
+        }
+    }
+    let lines: Vec<String> = reasons
+        .into_iter()
+        .map(|(reason, count)| format!("• {}× {}", count, reason))
+        .collect();
+
+    format!(
+        "⚠️ {} song(s) were not added to the queue:\n{}",
+        rejected.len(),
+        lines.join("\n")
+    )
+}
@@ -0,0 +1,89 @@
+use reqwest::StatusCode;
+use serde_derive::Deserialize;
+use serenity::async_trait;
+
+use crate::providers::music::lyrics::{Lyrics, LyricsProvider, LyricsQuery};
+use crate::utils::error::BotResult;
+
+const API_ENDPOINT: &str = "https://lrclib.net/api/";
+
+/// Plain and time-synced lyrics from lrclib.net
+pub struct LrcLib;
+
+#[async_trait]
+impl LyricsProvider for LrcLib {
+    fn name(&self) -> &'static str {
+        "lrclib"
+    }
+
+    async fn get_lyrics(&self, query: &LyricsQuery) -> BotResult<Option<Lyrics>> {
+        let client = reqwest::Client::builder()
+            .user_agent(format!("tobi-rs/{}", crate::VERSION))
+            .build()?;
+        let mut params = vec![
+            ("artist_name", query.artist.clone()),
+            ("track_name", query.title.clone()),
+        ];
+        if let Some(duration) = query.duration {
+            params.push(("duration", duration.as_secs().to_string()));
+        }
+        let response = client
+            .get(format!("{}get", API_ENDPOINT))
+            .query(&params)
+            .send()
+            .await?;
+
+        let entry = if response.status() == StatusCode::NOT_FOUND {
+            tracing::debug!("No exact match. Searching lrclib");
+            let search_query = format!("{} {}", query.artist, query.title);
+            let response_text = client
+                .get(format!("{}search", API_ENDPOINT))
+                .query(&[("q", search_query)])
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await?;
+            let results: Vec<LrcLibEntry> = serde_json::from_str(&response_text)?;
+            results.into_iter().find(|e| e.has_lyrics())
+        } else {
+            let response_text = response.error_for_status()?.text().await?;
+            Some(serde_json::from_str::<LrcLibEntry>(&response_text)?)
+        };
+
+        Ok(entry.and_then(|e| self.to_lyrics(e)))
+    }
+}
+
+impl LrcLib {
+    fn to_lyrics(&self, entry: LrcLibEntry) -> Option<Lyrics> {
+        let plain = match (entry.plain_lyrics, &entry.synced_lyrics) {
+            (Some(plain), _) => plain,
+            (None, Some(synced)) => bot_coreutils::lrc::parse_lrc(synced)
+                .into_iter()
+                .map(|l| l.text)
+                .collect::<Vec<String>>()
+                .join("\n"),
+            (None, None) => return None,
+        };
+
+        Some(Lyrics {
+            plain,
+            synced: entry.synced_lyrics,
+            provider: self.name().to_string(),
+        })
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct LrcLibEntry {
+    plain_lyrics: Option<String>,
+    synced_lyrics: Option<String>,
+}
+
+impl LrcLibEntry {
+    fn has_lyrics(&self) -> bool {
+        self.plain_lyrics.is_some() || self.synced_lyrics.is_some()
+    }
+}
@@ -0,0 +1,40 @@
+use regex::Regex;
+use serde_derive::Deserialize;
+use serenity::async_trait;
+
+use crate::providers::music::lyrics::{Lyrics, LyricsProvider, LyricsQuery};
+use crate::utils::error::BotResult;
+
+const API_ENDPOINT: &str = "https://api.lyrics.ovh/v1/";
+
+/// Plain lyrics from lyrics.ovh
+pub struct LyricsOvh;
+
+#[async_trait]
+impl LyricsProvider for LyricsOvh {
+    fn name(&self) -> &'static str {
+        "lyricsovh"
+    }
+
+    async fn get_lyrics(&self, query: &LyricsQuery) -> BotResult<Option<Lyrics>> {
+        lazy_static::lazy_static! { static ref DOUBLE_LB_REGEX: Regex = Regex::new(r"\n\n").unwrap(); }
+        let request_url = format!("{}{}/{}", API_ENDPOINT, query.artist, query.title);
+        tracing::trace!("Request url is {}", request_url);
+        let response = reqwest::get(request_url).await?;
+        let response_text = response.text().await?;
+        tracing::trace!("Lyrics Response is {}", response_text);
+
+        let lyrics: Option<LyricsResponse> = serde_json::from_str(&*response_text).ok();
+
+        Ok(lyrics.map(|l| Lyrics {
+            plain: DOUBLE_LB_REGEX.replace_all(&*l.lyrics, "\n").to_string(),
+            synced: None,
+            provider: self.name().to_string(),
+        }))
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+struct LyricsResponse {
+    lyrics: String,
+}
@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use bot_coreutils::lrc::{parse_lrc, LrcLine};
+use bot_database::Database;
+use regex::Regex;
+use serenity::async_trait;
+
+use crate::providers::music::queue::{Song, SongSource};
+use crate::utils::error::BotResult;
+
+use lrclib::LrcLib;
+use lyrics_ovh::LyricsOvh;
+
+pub mod lrclib;
+pub mod lyrics_ovh;
+
+/// The lyrics of a song
+#[derive(Clone, Debug)]
+pub struct Lyrics {
+    /// The lyrics as plain text
+    pub plain: String,
+    /// The lyrics in the LRC format if they're time-synced
+    pub synced: Option<String>,
+    /// The name of the provider the lyrics were fetched from
+    pub provider: String,
+}
+
+impl Lyrics {
+    /// Returns the time-synced lines of the lyrics
+    pub fn synced_lines(&self) -> Option<Vec<LrcLine>> {
+        self.synced
+            .as_ref()
+            .map(|s| parse_lrc(s))
+            .filter(|lines| !lines.is_empty())
+    }
+}
+
+/// The song to fetch lyrics for
+#[derive(Clone, Debug)]
+pub struct LyricsQuery {
+    pub artist: String,
+    pub title: String,
+    pub duration: Option<Duration>,
+}
+
+impl LyricsQuery {
+    /// Creates a query for the given song.
+    /// The artist is taken from the video title for youtube songs
+    /// as the uploader often isn't the artist
+    pub fn for_song(song: &Song) -> Self {
+        lazy_static::lazy_static! {
+            static ref BRACKETS_REGEX: Regex = Regex::new(r"\s*[(\[][^)\]]*[)\]]").unwrap();
+        }
+        let title = BRACKETS_REGEX.replace_all(song.title(), "").to_string();
+        let from_video = matches!(
+            song.source(),
+            SongSource::YouTube(_) | SongSource::Extractor { .. }
+        );
+
+        let (artist, title) = match title.split_once(" - ") {
+            Some((artist, title)) if from_video => (artist.to_string(), title.to_string()),
+            _ => (song.author().clone(), title),
+        };
+
+        Self {
+            artist: artist.trim().to_string(),
+            title: title.trim().to_string(),
+            duration: song.duration(),
+        }
+    }
+}
+
+/// A service that provides lyrics for songs
+#[async_trait]
+pub trait LyricsProvider: Send + Sync {
+    /// The name of the provider shown as the source of the lyrics
+    fn name(&self) -> &'static str;
+
+    /// Fetches the lyrics of a song
+    async fn get_lyrics(&self, query: &LyricsQuery) -> BotResult<Option<Lyrics>>;
+}
+
+/// Returns the lyrics providers in the order they're tried
+fn providers() -> Vec<Box<dyn LyricsProvider>> {
+    vec![Box::new(LrcLib), Box::new(LyricsOvh)]
+}
+
+/// Returns the lyrics of a song from the cache or the first provider that has them
+pub async fn get_lyrics(database: &Database, query: &LyricsQuery) -> BotResult<Option<Lyrics>> {
+    tracing::debug!("Requesting lyrics for '{}' by '{}'", query.title, query.artist);
+    let artist_key = query.artist.to_lowercase();
+    let title_key = query.title.to_lowercase();
+
+    if let Some(cached) = database.get_lyrics(&artist_key, &title_key).await? {
+        tracing::debug!("Using cached lyrics from {}", cached.provider);
+        return Ok(Some(Lyrics {
+            plain: cached.plain,
+            synced: cached.synced,
+            provider: cached.provider,
+        }));
+    }
+
+    for provider in providers() {
+        match provider.get_lyrics(query).await {
+            Ok(Some(lyrics)) => {
+                tracing::debug!("Found lyrics with {}", provider.name());
+                database
+                    .set_lyrics(
+                        artist_key,
+                        title_key,
+                        lyrics.provider.clone(),
+                        lyrics.plain.clone(),
+                        lyrics.synced.clone(),
+                    )
+                    .await?;
+                return Ok(Some(lyrics));
+            }
+            Ok(None) => tracing::debug!("No lyrics found with {}", provider.name()),
+            Err(e) => tracing::error!("Failed to fetch lyrics with {}: {:?}", provider.name(), e),
+        }
+    }
+
+    Ok(None)
+}
@@ -7,12 +7,22 @@ use regex::Regex;
 use responses::VideoInformation;
 use youtube_dl::search_video_information;
 
+pub mod autoplay;
+pub mod ffprobe;
+pub mod filters;
 pub mod inspirobot;
+pub mod limits;
 pub mod lyrics;
 pub mod player;
 pub mod player_events;
+pub mod playlists;
+pub mod podcasts;
+pub mod prefetch;
 pub mod queue;
+pub mod queue_export;
+pub mod resolver;
 pub mod responses;
+pub mod source;
 pub mod spotify;
 pub mod youtube_dl;
 
@@ -66,6 +76,9 @@ pub async fn add_youtube_song_to_database(
             }
             _ => return Ok(()),
         },
+        SongSource::SpotifyEpisode(_) | SongSource::Direct(_) | SongSource::Extractor { .. } => {
+            return Ok(())
+        }
     };
     tracing::debug!("Song found on spotify. Inserting metadata");
     let artists = artists_to_string(track.artists);
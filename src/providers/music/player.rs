@@ -1,19 +1,32 @@
-use crate::messages::music::now_playing::update_now_playing_msg;
-use crate::providers::music::lyrics::get_lyrics;
-use crate::providers::music::queue::MusicQueue;
-use crate::utils::context_data::MusicPlayers;
+use crate::messages::music::now_playing::{
+    create_now_playing_msg, update_now_playing_msg, NowPlayingInfo,
+};
+use crate::providers::music::autoplay::find_autoplay_song;
+use crate::providers::music::filters::{tempo, AudioFilter};
+use crate::providers::music::prefetch::Prefetcher;
+use crate::providers::music::source::{create_source, direct_stream_information};
+use crate::providers::music::queue::{LoopMode, MusicQueue, Song, SongSource};
+use crate::providers::settings::{get_setting, Setting};
+use crate::utils::context_data::{
+    get_database_from_context, DatabaseContainer, MusicPlayers, Store,
+};
 use crate::utils::error::{BotError, BotResult};
+use bot_database::models::MusicQueue as StoredMusicQueue;
+use bot_database::Database;
+use serenity::model::gateway::Ready;
 use serenity::prelude::TypeMap;
 use serenity::{
     client::Context,
     http::Http,
-    model::id::{ChannelId, GuildId},
+    model::id::{ChannelId, GuildId, UserId},
 };
 use serenity_additions::core::{MessageHandle, SHORT_TIMEOUT};
 use serenity_additions::ephemeral_message::EphemeralMessage;
 use songbird::tracks::TrackHandle;
 use songbird::Songbird;
+use std::collections::HashSet;
 use std::mem;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{Mutex, RwLock};
@@ -23,33 +36,72 @@ use super::player_events::register_player_events;
 pub struct MusicPlayer {
     manager: Arc<Songbird>,
     http: Arc<Http>,
+    data: Arc<RwLock<TypeMap>>,
+    database: Database,
     queue: MusicQueue,
     guild_id: GuildId,
     now_playing_msg: Option<Arc<RwLock<MessageHandle>>>,
+    voice_channel: ChannelId,
     msg_channel: ChannelId,
     leave_flag: bool,
     paused: bool,
+    skipped: bool,
+    volume: u32,
+    filters: Vec<AudioFilter>,
+    skip_votes: HashSet<UserId>,
+    required_skip_votes: usize,
+    prefetcher: Prefetcher,
+    crossfade: Duration,
+    /// Shared with the periodic crossfade handler so that it only
+    /// locks the player when crossfading is enabled
+    crossfade_enabled: Arc<AtomicBool>,
     current_track: Option<TrackHandle>,
+    fading_track: Option<TrackHandle>,
 }
 
+/// The maximum volume in percent that can be set
+pub static MAX_VOLUME: u32 = 200;
+
+/// The maximum crossfade duration in seconds
+static MAX_CROSSFADE: f32 = 12.0;
+
+/// The number of upcoming songs shown in the now playing message
+static UPCOMING_SONG_COUNT: usize = 25;
+
 impl MusicPlayer {
     /// Creates a new music player
     pub fn new(
         manager: Arc<Songbird>,
         http: Arc<Http>,
+        data: Arc<RwLock<TypeMap>>,
+        database: Database,
         guild_id: GuildId,
+        voice_channel: ChannelId,
         msg_channel: ChannelId,
+        volume: u32,
     ) -> Self {
         Self {
             manager,
             http,
+            data,
+            database,
             guild_id,
             queue: MusicQueue::new(),
+            voice_channel,
             msg_channel,
             now_playing_msg: None,
             leave_flag: false,
             paused: false,
+            skipped: false,
+            volume,
+            filters: Vec::new(),
+            skip_votes: HashSet::new(),
+            required_skip_votes: 0,
+            prefetcher: Prefetcher::default(),
+            crossfade: Duration::default(),
+            crossfade_enabled: Arc::new(AtomicBool::new(false)),
             current_track: None,
+            fading_track: None,
         }
     }
 
@@ -62,27 +114,42 @@ impl MusicPlayer {
     ) -> BotResult<Arc<Mutex<MusicPlayer>>> {
         let manager = songbird::get(ctx).await.unwrap();
         let (handler, _) = manager.join(guild_id, voice_channel_id).await;
-
-        let player = {
+        let volume = get_setting::<u32>(ctx, guild_id, Setting::MusicVolume)
+            .await?
+            .unwrap_or(100)
+            .min(MAX_VOLUME);
+        let fair_queue = get_setting::<bool>(ctx, guild_id, Setting::MusicFairQueue)
+            .await?
+            .unwrap_or(false);
+
+        let (player, crossfade_enabled) = {
             let mut data = ctx.data.write().await;
-            let player = MusicPlayer::new(
+            let database = data.get::<DatabaseContainer>().unwrap().clone();
+            let mut player = MusicPlayer::new(
                 Arc::clone(&manager),
                 Arc::clone(&ctx.http),
+                Arc::clone(&ctx.data),
+                database,
                 guild_id,
+                voice_channel_id,
                 msg_channel_id,
+                volume,
             );
+            player.queue.set_fair(fair_queue);
+            let crossfade_enabled = Arc::clone(&player.crossfade_enabled);
             let player = Arc::new(Mutex::new(player));
             let players = data.get_mut::<MusicPlayers>().unwrap();
             players.insert(guild_id.0, Arc::clone(&player));
-            player
+            (player, crossfade_enabled)
         };
 
         {
             let mut handler = handler.lock().await;
             handler.deafen(true).await?;
-            register_player_events(player.clone(), &mut handler);
+            register_player_events(player.clone(), crossfade_enabled, &mut handler);
         }
 
+        refresh_now_playing(Arc::clone(&player), Arc::clone(&manager), guild_id);
         wait_for_disconnect(
             Arc::clone(&ctx.data),
             Arc::clone(&player),
@@ -100,31 +167,113 @@ impl MusicPlayer {
 
     /// Skips to the next song
     pub async fn skip(&mut self) -> BotResult<()> {
-        if let Some(track) = self.current_track.take() {
+        self.finish_crossfade()?;
+
+        if let Some(track) = &self.current_track {
+            self.skipped = true;
             track.stop()?;
         }
 
         Ok(())
     }
 
+    /// Skips to the song at the given position of the queue
+    /// keeping the songs before it. Returns false if there's no such song
+    pub async fn jump_to(&mut self, index: usize) -> BotResult<bool> {
+        if index >= self.queue.entries().len() {
+            return Ok(false);
+        }
+        self.queue.move_position(index, 0);
+
+        if self.current_track.is_some() {
+            self.skip().await?;
+        } else {
+            self.play_next().await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Skips to the song at the given position of the queue
+    /// dropping the songs before it. Returns false if there's no such song
+    pub async fn skip_to(&mut self, index: usize) -> BotResult<bool> {
+        if !self.queue.skip_to(index) {
+            return Ok(false);
+        }
+
+        if self.current_track.is_some() {
+            self.skip().await?;
+        } else {
+            self.play_next().await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Plays the previous song and puts the current one back in front of the queue.
+    /// Returns false if there's no previous song
+    pub async fn previous(&mut self) -> BotResult<bool> {
+        if !self.queue.previous() {
+            return Ok(false);
+        }
+        self.finish_crossfade()?;
+        match &self.current_track {
+            Some(track) if track.stop().is_ok() => self.skipped = true,
+            _ => self.play_next().await?,
+        }
+
+        Ok(true)
+    }
+
     /// Stops playback and leaves the channel
     pub async fn stop(&mut self) -> BotResult<()> {
         self.queue.clear();
+        self.prefetcher.cancel();
+        self.finish_crossfade()?;
+        self.queue.set_loop_mode(LoopMode::Off);
         if let Some(track) = self.current_track.take() {
             track.stop()?;
         }
+        self.database.delete_music_queue(self.guild_id.0).await?;
+
         Ok(())
     }
 
-    /// Returns the lyrics for the currently playing song
-    pub async fn lyrics(&self) -> BotResult<Option<String>> {
-        if let Some(current) = self.queue.current() {
-            let title = current.title();
-            let artist = current.author();
-            get_lyrics(artist, title).await
-        } else {
-            Ok(None)
+    /// Needs to be called after the queue was changed.
+    /// Prefetches the upcoming songs and stores the state of the player
+    pub async fn on_queue_changed(&self) -> BotResult<()> {
+        self.prefetcher.prefetch(self.queue.entries());
+        self.save_state().await
+    }
+
+    /// Stores the state of the player in the database so that it
+    /// can be restored after a restart
+    pub async fn save_state(&self) -> BotResult<()> {
+        if self.queue.current().is_none() && self.queue.entries().is_empty() {
+            self.database.delete_music_queue(self.guild_id.0).await?;
+            return Ok(());
         }
+        let position = self.position().await.unwrap_or_default();
+        let current = self
+            .queue
+            .current()
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
+        let queue = serde_json::to_string(self.queue.entries())?;
+        self.database
+            .set_music_queue(
+                self.guild_id.0,
+                self.voice_channel.0,
+                self.msg_channel.0,
+                self.paused,
+                position.as_millis() as i64,
+                current,
+                queue,
+            )
+            .await?;
+
+        Ok(())
     }
 
     /// Plays the next song in the queue
@@ -135,18 +284,39 @@ impl MusicPlayer {
                 track.pause()?;
             }
         }
+        self.on_queue_changed().await?;
 
         Ok(())
     }
 
     /// Tries to play the next song
     pub async fn try_play_next(&mut self) -> BotResult<bool> {
-        let mut next = if let Some(n) = self.queue.next() {
+        let next = if mem::take(&mut self.skipped) {
+            self.queue.skip()
+        } else {
+            self.queue.next()
+        };
+        let mut next = if let Some(n) = next {
             tracing::trace!("Next is {:?}", n);
             n
+        } else if let Some(song) = self.autoplay_song().await {
+            tracing::trace!("Autoplaying {:?}", song);
+            song
         } else {
+            // the finished track can't be stopped anymore when skipping
+            self.current_track = None;
             return Ok(true);
         };
+        let mut information = None;
+
+        if let Some(prefetched) = self.prefetcher.take(&next) {
+            tracing::debug!("Using prefetched song");
+            next = prefetched.song;
+            information = prefetched.information;
+        }
+        if information.is_none() {
+            information = direct_stream_information(&next);
+        }
         let url = if let Some(url) = next.url().await {
             url
         } else {
@@ -157,9 +327,11 @@ impl MusicPlayer {
             ))
             .await?;
             tracing::debug!("Could not find playable candidate for song.");
-            return Ok(false);
+            // autoplay songs aren't retried to avoid searching endlessly
+            return Ok(next.is_autoplay());
         };
-        let source = match songbird::ytdl(url).await {
+        let reusable = matches!(next.source(), SongSource::Direct(_));
+        let source = match create_source(url, self.filters.clone(), information, reusable).await {
             Ok(s) => s,
             Err(e) => {
                 tracing::error!("Failed to search for song: {}", e);
@@ -170,7 +342,7 @@ impl MusicPlayer {
                     e
                 ))
                 .await?;
-                return Ok(false);
+                return Ok(next.is_autoplay());
             }
         };
         let handler_lock = self
@@ -180,14 +352,135 @@ impl MusicPlayer {
         {
             let mut handler = handler_lock.lock().await;
             let track_handle = handler.play_source(source);
+            // tracks fading in start muted
+            if self.fading_track.is_some() {
+                track_handle.set_volume(0.0)?;
+            } else {
+                track_handle.set_volume(self.volume as f32 / 100.0)?;
+            }
             self.current_track = Some(track_handle);
         }
 
         self.queue.set_current(next);
+        self.skip_votes.clear();
+        self.prefetcher.prefetch(self.queue.entries());
+        self.crossfade = self.crossfade_duration().await;
+        self.crossfade_enabled
+            .store(!self.crossfade.is_zero(), Ordering::Relaxed);
 
         Ok(true)
     }
 
+    /// Returns the configured crossfade duration
+    async fn crossfade_duration(&self) -> Duration {
+        let seconds = self
+            .database
+            .get_guild_setting::<f32, _>(self.guild_id.0, Setting::MusicCrossfade.to_string())
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or(0.0);
+
+        Duration::from_secs_f32(seconds.max(0.0).min(MAX_CROSSFADE))
+    }
+
+    /// Starts playing the next song while the current one fades out when
+    /// the current song is about to end and updates the volumes of an
+    /// ongoing crossfade. Called periodically while the player is active
+    pub async fn update_crossfade(&mut self) -> BotResult<()> {
+        if self.crossfade.is_zero() || self.paused {
+            return Ok(());
+        }
+        let volume = self.volume as f32 / 100.0;
+
+        if let Some(fading_track) = &self.fading_track {
+            let position = self.position().await.unwrap_or_default();
+            let progress =
+                position.div_f64(self.tempo()).as_secs_f32() / self.crossfade.as_secs_f32();
+
+            if progress >= 1.0 {
+                self.finish_crossfade()?;
+            } else {
+                let _ = fading_track.set_volume(volume * (1.0 - progress));
+
+                if let Some(track) = &self.current_track {
+                    track.set_volume(volume * progress)?;
+                }
+            }
+            return Ok(());
+        }
+        let (position, duration) = match (self.position().await, self.duration()) {
+            (Some(position), Some(duration)) => (position, duration),
+            _ => return Ok(()),
+        };
+        let has_next = !self.queue.entries().is_empty() || self.queue.loop_mode() != LoopMode::Off;
+
+        if !has_next || duration.saturating_sub(position).div_f64(self.tempo()) > self.crossfade {
+            return Ok(());
+        }
+        tracing::debug!("Crossfading into the next song");
+        self.fading_track = self.current_track.take();
+        self.play_next().await?;
+        self.update_now_playing().await?;
+
+        Ok(())
+    }
+
+    /// Stops the track that is fading out and restores the volume of the current track
+    fn finish_crossfade(&mut self) -> BotResult<()> {
+        if let Some(track) = self.fading_track.take() {
+            // the track might have ended already
+            let _ = track.stop();
+
+            if let Some(track) = &self.current_track {
+                track.set_volume(self.volume as f32 / 100.0)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Forgets the fading track if it's the given one that ended
+    pub fn fading_track_ended(&mut self, track: &TrackHandle) {
+        if self
+            .fading_track
+            .as_ref()
+            .map(|t| t.uuid() == track.uuid())
+            .unwrap_or(false)
+        {
+            self.fading_track = None;
+        }
+    }
+
+    /// Returns a song related to the recently played ones if autoplay is enabled
+    async fn autoplay_song(&self) -> Option<Song> {
+        let autoplay = self
+            .database
+            .get_guild_setting::<bool, _>(self.guild_id.0, Setting::MusicAutoplay.to_string())
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or(false);
+
+        if !autoplay {
+            return None;
+        }
+        // the data isn't locked during the requests so that commands aren't blocked by them
+        let spotify = {
+            let data = self.data.read().await;
+            Arc::clone(&data.get::<Store>().unwrap().spotify_api)
+        };
+        let history = self.queue.history().iter().cloned().collect();
+
+        match find_autoplay_song(&self.database, &spotify, history).await {
+            Ok(song) => song,
+            Err(e) => {
+                tracing::error!("Failed to find a song for autoplay: {:?}", e);
+                None
+            }
+        }
+    }
+
     /// Sets the new now playing message of the queue
     pub async fn set_now_playing(&mut self, message: Arc<RwLock<MessageHandle>>) {
         let _ = self.delete_now_playing().await;
@@ -196,13 +489,104 @@ impl MusicPlayer {
 
     /// Updates the now playing message
     pub async fn update_now_playing(&self) -> BotResult<()> {
-        if let (Some(current), Some(np)) = (self.queue.current(), &self.now_playing_msg) {
-            update_now_playing_msg(&self.http, np, &mut current.clone(), self.is_paused()).await?;
+        if let Some(np) = &self.now_playing_msg {
+            if let Some(mut info) = self.now_playing_info().await {
+                update_now_playing_msg(&self.http, np, &mut info).await?;
+            }
         }
 
         Ok(())
     }
 
+    /// Returns the information displayed in the now playing message
+    pub async fn now_playing_info(&self) -> Option<NowPlayingInfo> {
+        let song = self.queue.current().clone()?;
+
+        Some(NowPlayingInfo {
+            song,
+            paused: self.paused,
+            loop_mode: self.queue.loop_mode(),
+            volume: self.volume,
+            filters: self.filters.clone(),
+            skip_votes: if self.skip_votes.is_empty() {
+                None
+            } else {
+                Some((self.skip_votes.len(), self.required_skip_votes))
+            },
+            position: self.position().await,
+            duration: self.duration(),
+            upcoming: self
+                .queue
+                .entries()
+                .iter()
+                .take(UPCOMING_SONG_COUNT)
+                .cloned()
+                .collect(),
+        })
+    }
+
+    /// Returns the playback position of the current track in the timeline of the song
+    pub async fn position(&self) -> Option<Duration> {
+        let track = self.current_track.as_ref()?;
+        let info = track.get_info().await.ok()?;
+
+        Some(info.position.mul_f64(self.tempo()))
+    }
+
+    /// Returns the factor by which the filters speed up playback
+    fn tempo(&self) -> f64 {
+        tempo(&self.filters)
+    }
+
+    /// Returns the total duration of the current track if known
+    pub fn duration(&self) -> Option<Duration> {
+        self.current_track.as_ref()?.metadata().duration
+    }
+
+    /// Returns the remaining playback time of the current track if known
+    /// taking the tempo of the filters into account
+    pub async fn remaining(&self) -> Option<Duration> {
+        if self.current_track.is_none() {
+            return Some(Duration::ZERO);
+        }
+        let duration = self
+            .duration()
+            .or_else(|| self.queue.current().as_ref()?.duration())?;
+        let position = self.position().await?;
+
+        Some(duration.saturating_sub(position).div_f64(self.tempo()))
+    }
+
+    /// Seeks to the given position of the current track.
+    /// Returns the position that was actually seeked to
+    pub async fn seek(&mut self, position: Duration) -> BotResult<Duration> {
+        let track = self
+            .current_track
+            .as_ref()
+            .ok_or(BotError::from("Nothing is playing"))?;
+        let position = if let Some(duration) = track.metadata().duration {
+            position.min(duration)
+        } else {
+            position
+        };
+        track.seek_time(position.div_f64(self.tempo()))?;
+        self.save_state().await?;
+
+        Ok(position)
+    }
+
+    /// Moves the position of the current track forward by the given amount
+    pub async fn forward(&mut self, amount: Duration) -> BotResult<Duration> {
+        let position = self.position().await.unwrap_or_default();
+        self.seek(position.saturating_add(amount)).await
+    }
+
+    /// Moves the position of the current track back by the given amount
+    pub async fn rewind(&mut self, amount: Duration) -> BotResult<Duration> {
+        let position = self.position().await.unwrap_or_default();
+        self.seek(position.saturating_sub(amount)).await
+    }
+
     /// Deletes the now playing message
     pub async fn delete_now_playing(&mut self) -> BotResult<()> {
         if let Some(np) = mem::take(&mut self.now_playing_msg) {
@@ -217,6 +601,7 @@ impl MusicPlayer {
     /// Pauses playback
     pub async fn toggle_paused(&mut self) -> BotResult<()> {
         self.paused = !self.paused;
+        self.finish_crossfade()?;
         if let Some(track) = self.current_track.as_ref() {
             if self.paused {
                 track.pause()?;
@@ -224,10 +609,85 @@ impl MusicPlayer {
                 track.play()?;
             }
         }
+        self.save_state().await?;
+
+        Ok(())
+    }
+
+    /// Sets the volume in percent for the current and all following tracks
+    pub fn set_volume(&mut self, volume: u32) -> BotResult<()> {
+        self.volume = volume.min(MAX_VOLUME);
+
+        if let Some(track) = &self.current_track {
+            track.set_volume(self.volume as f32 / 100.0)?;
+        }
 
         Ok(())
     }
 
+    /// Returns the volume in percent
+    pub fn volume(&self) -> u32 {
+        self.volume
+    }
+
+    /// Sets the audio filters and recreates the current track
+    /// at the current position with the new filters applied
+    pub async fn set_filters(&mut self, filters: Vec<AudioFilter>) -> BotResult<()> {
+        // the position depends on the tempo of the previous filters
+        let position = self.position().await.unwrap_or_default();
+        self.filters = filters;
+        self.reload_track(position).await
+    }
+
+    /// Replaces the current track with a new one created for the current song
+    /// that continues at the given position
+    async fn reload_track(&mut self, position: Duration) -> BotResult<()> {
+        let mut song = if let Some(song) = self.queue.current().clone() {
+            song
+        } else {
+            return Ok(());
+        };
+        let url = song.url().await.ok_or(BotError::from("Song not found"))?;
+        let information = direct_stream_information(&song);
+        let reusable = information.is_some();
+        let source = create_source(url, self.filters.clone(), information, reusable)
+            .await
+            .map_err(|e| BotError::Msg(format!("Failed to recreate track: {:?}", e)))?;
+        let handler_lock = self
+            .manager
+            .get(self.guild_id.0)
+            .ok_or(BotError::MissingSongbirdClient)?;
+        let track_handle = {
+            let mut handler = handler_lock.lock().await;
+            handler.play_source(source)
+        };
+        track_handle.set_volume(self.volume as f32 / 100.0)?;
+        track_handle.seek_time(position.div_f64(self.tempo()))?;
+
+        if self.paused {
+            track_handle.pause()?;
+        }
+        // the old track is replaced before stopping it so that its end event is ignored
+        if let Some(old_track) = self.current_track.replace(track_handle) {
+            let _ = old_track.stop();
+        }
+
+        Ok(())
+    }
+
+    /// Returns if the given track is the currently playing one
+    pub fn is_current_track(&self, track: &TrackHandle) -> bool {
+        self.current_track
+            .as_ref()
+            .map(|t| t.uuid() == track.uuid())
+            .unwrap_or(false)
+    }
+
+    /// Returns if a track is loaded
+    pub fn has_track(&self) -> bool {
+        self.current_track.is_some()
+    }
+
     /// Returns if playback is paused
     pub fn is_paused(&self) -> bool {
         self.paused
@@ -243,6 +703,33 @@ impl MusicPlayer {
         self.now_playing_msg = None;
     }
 
+    /// Adds a vote to skip the current song and sets the number of
+    /// votes required. Votes of users that aren't listening anymore are dropped.
+    /// Returns the number of votes
+    pub fn vote_skip(
+        &mut self,
+        user_id: UserId,
+        listeners: &HashSet<UserId>,
+        required: usize,
+    ) -> usize {
+        self.skip_votes.retain(|id| listeners.contains(id));
+        self.skip_votes.insert(user_id);
+        self.required_skip_votes = required;
+
+        self.skip_votes.len()
+    }
+
+    /// Returns the voice channel the player is connected to
+    pub fn voice_channel(&self) -> ChannelId {
+        self.voice_channel
+    }
+
+    /// Sets the voice channel the player is connected to
+    pub async fn set_voice_channel(&mut self, channel_id: ChannelId) -> BotResult<()> {
+        self.voice_channel = channel_id;
+        self.save_state().await
+    }
+
     /// Sets the leave flag to the given value
     pub fn set_leave_flag(&mut self, flag: bool) {
         self.leave_flag = flag;
@@ -259,6 +746,26 @@ impl MusicPlayer {
     }
 }
 
+/// Starts a tokio coroutine that periodically updates the now playing message
+/// to keep the displayed position up to date
+fn refresh_now_playing(player: Arc<Mutex<MusicPlayer>>, manager: Arc<Songbird>, guild_id: GuildId) {
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            if manager.get(guild_id).is_none() {
+                return;
+            }
+            let player = player.lock().await;
+
+            if !player.is_paused() {
+                if let Err(e) = player.update_now_playing().await {
+                    tracing::debug!("Failed to refresh now playing message: {:?}", e);
+                }
+            }
+        }
+    });
+}
+
 /// Stats a tokio coroutine to check for player disconnect conditions
 fn wait_for_disconnect(
     data: Arc<RwLock<TypeMap>>,
@@ -302,8 +809,102 @@ fn wait_for_disconnect(
                 leave_in -= 1;
             } else {
                 tracing::debug!("Resetting leave value");
-                leave_in = 5
+                leave_in = 5;
+
+                if let Err(e) = player_lock.save_state().await {
+                    tracing::error!("Failed to save player state: {:?}", e);
+                }
             }
         }
     });
 }
+
+/// Restores all music players of the guilds handled by the shard
+/// that were active when the bot was stopped
+pub async fn restore_music_players(ctx: &Context, ready: &Ready) -> BotResult<()> {
+    let database = get_database_from_context(ctx).await;
+    let stored_queues = database.get_music_queues().await?;
+
+    for stored in stored_queues {
+        let guild_id = GuildId(stored.guild_id as u64);
+
+        if !ready.guilds.iter().any(|g| g.id == guild_id) {
+            continue;
+        }
+        // ready is sent again when the session is re-identified
+        // while the existing players keep running
+        let has_player = {
+            let data = ctx.data.read().await;
+            let players = data.get::<MusicPlayers>().unwrap();
+            players.contains_key(&guild_id.0)
+        };
+        if has_player {
+            tracing::debug!("Music player for guild {} is still running", guild_id);
+            continue;
+        }
+        tracing::info!("Restoring music player for guild {}", guild_id);
+
+        if let Err(e) = restore_music_player(ctx, guild_id, stored).await {
+            tracing::error!("Failed to restore music player: {:?}", e);
+
+            if let Err(e) = database.delete_music_queue(guild_id.0).await {
+                tracing::error!("Failed to delete stored queue: {:?}", e);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Rejoins the voice channel of a stored music queue and resumes playback
+async fn restore_music_player(
+    ctx: &Context,
+    guild_id: GuildId,
+    stored: StoredMusicQueue,
+) -> BotResult<()> {
+    let current: Option<Song> = stored
+        .current_song
+        .map(|s| serde_json::from_str(&s))
+        .transpose()?;
+    let songs: Vec<Song> = serde_json::from_str(&stored.queue)?;
+
+    if current.is_none() && songs.is_empty() {
+        tracing::debug!("Stored queue is empty");
+        get_database_from_context(ctx)
+            .await
+            .delete_music_queue(guild_id.0)
+            .await?;
+        return Ok(());
+    }
+    let msg_channel = ChannelId(stored.msg_channel_id as u64);
+    let player = MusicPlayer::join(
+        ctx,
+        guild_id,
+        ChannelId(stored.voice_channel_id as u64),
+        msg_channel,
+    )
+    .await?;
+
+    {
+        let mut player = player.lock().await;
+        player.paused = stored.paused;
+        // the songs aren't added one by one as that would reorder them in fair mode
+        player.queue.restore(current, songs);
+        player.play_next().await?;
+
+        if stored.position > 0 {
+            if let Some(track) = &player.current_track {
+                let position = Duration::from_millis(stored.position as u64);
+
+                if let Err(e) = track.seek_time(position.div_f64(player.tempo())) {
+                    tracing::debug!("Failed to resume at the last position: {:?}", e);
+                }
+            }
+        }
+    }
+    let handle = create_now_playing_msg(ctx, Arc::clone(&player), msg_channel).await?;
+    let mut player = player.lock().await;
+    player.set_now_playing(handle).await;
+
+    Ok(())
+}
@@ -1,13 +1,34 @@
 use serenity::async_trait;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use serenity::prelude::Mutex;
 use songbird::{Call, Event, EventContext, EventHandler, TrackEvent};
 
 use super::player::MusicPlayer;
 
-pub fn register_player_events(player: Arc<Mutex<MusicPlayer>>, handler: &mut Call) {
-    handler.add_global_event(Event::Track(TrackEvent::End), TrackEndHandler { player });
+/// The interval in which the volumes of crossfading tracks are updated
+static CROSSFADE_INTERVAL: Duration = Duration::from_millis(250);
+
+pub fn register_player_events(
+    player: Arc<Mutex<MusicPlayer>>,
+    crossfade_enabled: Arc<AtomicBool>,
+    handler: &mut Call,
+) {
+    handler.add_global_event(
+        Event::Track(TrackEvent::End),
+        TrackEndHandler {
+            player: Arc::clone(&player),
+        },
+    );
+    handler.add_global_event(
+        Event::Periodic(CROSSFADE_INTERVAL, None),
+        CrossfadeHandler {
+            player,
+            enabled: crossfade_enabled,
+        },
+    );
 }
 
 struct TrackEndHandler {
@@ -17,8 +38,18 @@ struct TrackEndHandler {
 #[async_trait]
 impl EventHandler for TrackEndHandler {
     #[tracing::instrument(level = "debug", skip_all)]
-    async fn act(&self, _ctx: &EventContext<'_>) -> Option<Event> {
+    async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
         let mut player = self.player.lock().await;
+
+        if let EventContext::Track(tracks) = ctx {
+            for (_, track) in tracks.iter() {
+                player.fading_track_ended(track);
+            }
+            if !tracks.iter().any(|(_, t)| player.is_current_track(t)) {
+                tracing::debug!("Ended track is not the current one. Ignoring");
+                return None;
+            }
+        }
         if let Err(e) = player.play_next().await {
             tracing::error!("Failed to play next song: {:?}", e);
         }
@@ -28,3 +59,23 @@ impl EventHandler for TrackEndHandler {
         None
     }
 }
+
+struct CrossfadeHandler {
+    player: Arc<Mutex<MusicPlayer>>,
+    enabled: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl EventHandler for CrossfadeHandler {
+    async fn act(&self, _: &EventContext<'_>) -> Option<Event> {
+        if !self.enabled.load(Ordering::Relaxed) {
+            return None;
+        }
+        let mut player = self.player.lock().await;
+
+        if let Err(e) = player.update_crossfade().await {
+            tracing::error!("Failed to update crossfade: {:?}", e);
+        }
+        None
+    }
+}
@@ -0,0 +1,127 @@
+use bot_database::models::{Playlist, UserPlaylist};
+use bot_database::Database;
+use serenity::model::id::{GuildId, UserId};
+
+use crate::providers::music::queue::Song;
+use crate::utils::error::{BotError, BotResult};
+
+/// Returns the stored playlist with the given name
+pub async fn get_playlist(database: &Database, guild_id: GuildId, name: &str) -> BotResult<Playlist> {
+    database
+        .get_playlist(guild_id.0, name)
+        .await?
+        .ok_or_else(|| BotError::Msg(format!("There's no playlist named **{}**", name)))
+}
+
+/// Returns the songs of a stored playlist skipping the ones that can't be read
+pub async fn get_playlist_songs(database: &Database, playlist: &Playlist) -> BotResult<Vec<Song>> {
+    let entries = database.get_playlist_songs(playlist.id).await?;
+
+    Ok(deserialize_songs(entries.into_iter().map(|e| e.song)))
+}
+
+/// Replaces the songs of a stored playlist
+pub async fn set_playlist_songs(
+    database: &Database,
+    playlist: &Playlist,
+    songs: Vec<Song>,
+) -> BotResult<()> {
+    database
+        .set_playlist_songs(playlist.id, serialize_songs(songs)?)
+        .await?;
+
+    Ok(())
+}
+
+/// Returns the private playlist of a user with the given name
+pub async fn get_user_playlist(
+    database: &Database,
+    user_id: UserId,
+    name: &str,
+) -> BotResult<UserPlaylist> {
+    database
+        .get_user_playlist(user_id.0, name)
+        .await?
+        .ok_or_else(|| BotError::Msg(format!("You have no playlist named **{}**", name)))
+}
+
+/// Returns the songs of a private playlist skipping the ones that can't be read
+pub async fn get_user_playlist_songs(
+    database: &Database,
+    playlist: &UserPlaylist,
+) -> BotResult<Vec<Song>> {
+    let entries = database.get_user_playlist_songs(playlist.id).await?;
+
+    Ok(deserialize_songs(entries.into_iter().map(|e| e.song)))
+}
+
+/// Replaces the songs of a private playlist
+pub async fn set_user_playlist_songs(
+    database: &Database,
+    playlist: &UserPlaylist,
+    songs: Vec<Song>,
+) -> BotResult<()> {
+    database
+        .set_user_playlist_songs(playlist.id, serialize_songs(songs)?)
+        .await?;
+
+    Ok(())
+}
+
+/// Returns the favorite songs of a user with their ids
+pub async fn get_favorites(database: &Database, user_id: UserId) -> BotResult<Vec<(i64, Song)>> {
+    let favorites = database
+        .get_favorites(user_id.0)
+        .await?
+        .into_iter()
+        .filter_map(|f| Some((f.id, deserialize_songs(vec![f.song]).pop()?)))
+        .collect();
+
+    Ok(favorites)
+}
+
+/// Adds a song to the favorites of a user.
+/// Returns false if the song already is a favorite
+pub async fn add_favorite(database: &Database, user_id: UserId, song: Song) -> BotResult<bool> {
+    let favorites = get_favorites(database, user_id).await?;
+
+    if favorites
+        .iter()
+        .any(|(_, f)| f.title() == song.title() && f.author() == song.author())
+    {
+        return Ok(false);
+    }
+    let serialized = serialize_songs(vec![song])?.remove(0);
+    database.add_favorite(user_id.0, serialized).await?;
+
+    Ok(true)
+}
+
+/// Serializes songs for storing them without information about the current queue
+fn serialize_songs(songs: Vec<Song>) -> BotResult<Vec<String>> {
+    let songs = songs
+        .into_iter()
+        .map(|mut song| {
+            song.requester = None;
+            song.added_at = None;
+            song.autoplay = false;
+            serde_json::to_string(&song)
+        })
+        .collect::<Result<Vec<String>, _>>()?;
+
+    Ok(songs)
+}
+
+/// Deserializes stored songs skipping the ones that can't be read
+fn deserialize_songs<I: IntoIterator<Item = String>>(songs: I) -> Vec<Song> {
+    songs
+        .into_iter()
+        .filter_map(|song| match serde_json::from_str::<Song>(&song) {
+            Ok(song) => Some(song),
+            Err(e) => {
+                tracing::error!("Failed to read stored song: {:?}", e);
+                None
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,104 @@
+use bot_coreutils::url::is_remote_http;
+use regex::Regex;
+use serde_derive::Deserialize;
+
+use crate::providers::music::queue_export::unescape_xml;
+use crate::utils::error::BotResult;
+
+const PODCAST_SEARCH_ENDPOINT: &str = "https://itunes.apple.com/search";
+
+/// The number of shows whose feeds are searched for an episode
+static SHOW_SEARCH_LIMIT: usize = 3;
+
+/// The minimum similarity of names that are considered the same
+static MIN_SIMILARITY: f32 = 0.8;
+
+/// Finds the audio file of a podcast episode in the rss feed of its show.
+/// Returns none if no episode with a matching title is found as
+/// podcasts usually aren't available on youtube
+pub async fn find_episode_audio(show: &str, episode: &str) -> BotResult<Option<String>> {
+    tracing::debug!("Searching feed of '{}' for episode '{}'", show, episode);
+    let client = reqwest::Client::builder()
+        .user_agent(format!("tobi-rs/{}", crate::VERSION))
+        .build()?;
+    let response_text = client
+        .get(PODCAST_SEARCH_ENDPOINT)
+        .query(&[("media", "podcast"), ("entity", "podcast"), ("term", show)])
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let results: PodcastSearchResults = serde_json::from_str(&response_text)?;
+    let feeds = results
+        .results
+        .into_iter()
+        .filter(|r| is_similar(&r.collection_name, show))
+        .filter_map(|r| r.feed_url)
+        .take(SHOW_SEARCH_LIMIT);
+
+    for feed in feeds {
+        let content = match client.get(&feed).send().await.and_then(|r| r.error_for_status()) {
+            Ok(response) => response.text().await?,
+            Err(e) => {
+                tracing::debug!("Failed to fetch feed {}: {:?}", feed, e);
+                continue;
+            }
+        };
+        if let Some(url) = find_episode_in_feed(&content, episode) {
+            return Ok(Some(url));
+        }
+    }
+    tracing::debug!("Episode not found in any feed");
+
+    Ok(None)
+}
+
+/// Returns the enclosure url of the feed item with the given title
+fn find_episode_in_feed(content: &str, episode: &str) -> Option<String> {
+    lazy_static::lazy_static! {
+        static ref ITEM_REGEX: Regex = Regex::new(r"(?s)<item\b[^>]*>(.*?)</item>").unwrap();
+        static ref TITLE_REGEX: Regex = Regex::new(r"(?s)<title>(?:<!\[CDATA\[)?(.*?)(?:\]\]>)?</title>").unwrap();
+        static ref ENCLOSURE_REGEX: Regex = Regex::new(r#"<enclosure\b[^>]*\burl\s*=\s*["']([^"']+)["']"#).unwrap();
+    }
+
+    ITEM_REGEX
+        .captures_iter(content)
+        .filter(|item| {
+            TITLE_REGEX
+                .captures(&item[1])
+                .map(|title| is_similar(&unescape_xml(title[1].trim()), episode))
+                .unwrap_or(false)
+        })
+        .filter_map(|item| {
+            let url = unescape_xml(&ENCLOSURE_REGEX.captures(&item[1])?[1]);
+            Some(url).filter(|url| is_remote_http(url))
+        })
+        .next()
+}
+
+/// Returns if two names are the same ignoring case and special characters
+fn is_similar(a: &str, b: &str) -> bool {
+    let normalize = |s: &str| {
+        s.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .collect::<Vec<&str>>()
+            .join(" ")
+    };
+    let (a, b) = (normalize(a), normalize(b));
+
+    a == b || trigram::similarity(&a, &b) >= MIN_SIMILARITY
+}
+
+#[derive(Deserialize, Clone, Debug)]
+struct PodcastSearchResults {
+    results: Vec<PodcastSearchResult>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct PodcastSearchResult {
+    collection_name: String,
+    feed_url: Option<String>,
+}
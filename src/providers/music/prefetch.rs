@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use tokio::task::JoinHandle;
+
+use crate::providers::music::queue::{Song, SongSource};
+use crate::providers::music::youtube_dl::get_audio_information;
+
+/// The number of upcoming songs that are resolved in the background
+static PREFETCH_COUNT: usize = 2;
+
+/// A song that has been resolved ahead of playback
+#[derive(Clone, Debug)]
+pub struct PrefetchedSong {
+    pub song: Song,
+    pub information: Option<serde_json::Value>,
+}
+
+/// Resolves the upcoming songs of a queue in the background
+/// so that they can be played without delay
+#[derive(Default)]
+pub struct Prefetcher {
+    task: Mutex<Option<(Vec<String>, JoinHandle<()>)>>,
+    cache: Arc<Mutex<HashMap<String, PrefetchedSong>>>,
+}
+
+impl Prefetcher {
+    /// Starts resolving the given upcoming songs cancelling the previous prefetch.
+    /// Results for songs that are no longer upcoming are dropped
+    pub fn prefetch<'a, I: IntoIterator<Item = &'a Song>>(&self, upcoming: I) {
+        let songs: Vec<Song> = upcoming.into_iter().take(PREFETCH_COUNT).cloned().collect();
+        let keys: Vec<String> = songs.iter().map(song_key).collect();
+
+        if let Some((running_keys, handle)) = &*self.task.lock().unwrap() {
+            if running_keys == &keys && !handle.is_finished() {
+                return;
+            }
+        }
+        self.cancel_task();
+
+        let songs: Vec<Song> = {
+            let mut cache = self.cache.lock().unwrap();
+            cache.retain(|k, _| keys.contains(k));
+            songs
+                .into_iter()
+                .filter(|s| !cache.contains_key(&song_key(s)))
+                // direct audio urls don't need to be resolved
+                .filter(|s| !matches!(s.source(), SongSource::Direct(_)))
+                .collect()
+        };
+        if songs.is_empty() {
+            return;
+        }
+        tracing::debug!("Prefetching {} songs", songs.len());
+        let cache = Arc::clone(&self.cache);
+
+        let handle = tokio::spawn(async move {
+            for mut song in songs {
+                let key = song_key(&song);
+                let information = if let Some(url) = song.url().await {
+                    get_audio_information(&url).await.ok()
+                } else {
+                    tracing::debug!("Could not resolve song '{}'", song.title());
+                    continue;
+                };
+                tracing::trace!("Prefetched song {:?}", song);
+                cache
+                    .lock()
+                    .unwrap()
+                    .insert(key, PrefetchedSong { song, information });
+            }
+        });
+        *self.task.lock().unwrap() = Some((keys, handle));
+    }
+
+    /// Returns the prefetched version of the song if it has been resolved already
+    pub fn take(&self, song: &Song) -> Option<PrefetchedSong> {
+        self.cache.lock().unwrap().remove(&song_key(song))
+    }
+
+    /// Cancels the prefetch and drops all resolved songs
+    pub fn cancel(&self) {
+        self.cancel_task();
+        self.cache.lock().unwrap().clear();
+    }
+
+    /// Aborts the running prefetch task
+    fn cancel_task(&self) {
+        if let Some((_, handle)) = self.task.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+}
+
+impl Drop for Prefetcher {
+    fn drop(&mut self) {
+        self.cancel_task();
+    }
+}
+
+/// Returns a key identifying the song in the prefetch cache
+fn song_key(song: &Song) -> String {
+    match song.source() {
+        SongSource::Spotify(track) => track
+            .id
+            .clone()
+            .unwrap_or_else(|| format!("{} - {}", song.author(), song.title())),
+        SongSource::SpotifyEpisode(id) => id.clone(),
+        SongSource::YouTube(url)
+        | SongSource::Direct(url)
+        | SongSource::Extractor { url, .. } => url.clone(),
+    }
+}
@@ -1,17 +1,32 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
 
-use aspotify::Track;
+use aspotify::{Episode, EpisodeSimplified, Track};
+use serde_derive::{Deserialize, Serialize};
+use serenity::model::id::UserId;
 
-use bot_coreutils::shuffle::Shuffle;
+use bot_coreutils::selection::{dedupe_by_key, remove_indices};
+use bot_coreutils::shuffle::{Shuffle, SmartShuffle};
+use bot_coreutils::url::get_domain_for_url;
 
-use crate::providers::music::responses::{PlaylistEntry, VideoInformation};
+use crate::providers::music::podcasts::find_episode_audio;
+use crate::providers::music::responses::{ExtractorInformation, PlaylistEntry, VideoInformation};
 use crate::providers::music::song_to_youtube_video;
 use bot_database::models::YoutubeSong;
 
+/// The maximum number of songs kept in the playback history
+static HISTORY_LIMIT: usize = 50;
+
 #[derive(Clone)]
 pub struct MusicQueue {
     inner: VecDeque<Song>,
     current: Option<Song>,
+    history: VecDeque<Song>,
+    undo: Option<VecDeque<Song>>,
+    loop_mode: LoopMode,
+    fair: bool,
 }
 
 impl MusicQueue {
@@ -19,22 +34,118 @@ impl MusicQueue {
         Self {
             inner: VecDeque::new(),
             current: None,
+            history: VecDeque::new(),
+            undo: None,
+            loop_mode: LoopMode::Off,
+            fair: false,
+        }
+    }
+
+    /// Adds a song to the queue.
+    /// In fair mode the song is inserted at the end of the next
+    /// round in which its requester has no song queued yet
+    pub fn add(&mut self, mut song: Song) {
+        song.added_at.get_or_insert_with(SystemTime::now);
+        self.undo = None;
+
+        if !self.fair {
+            self.inner.push_back(song);
+            return;
         }
+        let rounds = self.rounds();
+        let song_round = self
+            .current
+            .iter()
+            .chain(self.inner.iter())
+            .filter(|s| s.requester == song.requester)
+            .count();
+        let index = rounds
+            .iter()
+            .position(|r| *r > song_round)
+            .unwrap_or(self.inner.len());
+        self.inner.insert(index, song);
     }
 
-    /// Adds a song to the queue
-    pub fn add(&mut self, song: Song) {
-        self.inner.push_back(song);
+    /// Replaces the queued songs with stored ones keeping their order.
+    /// The stored current song is put in front to be played next
+    pub fn restore(&mut self, current: Option<Song>, songs: Vec<Song>) {
+        self.inner = current.into_iter().chain(songs).collect();
+        self.undo = None;
     }
 
     /// Adds a song to be played next in the queue
-    pub fn add_next(&mut self, song: Song) {
+    pub fn add_next(&mut self, mut song: Song) {
+        song.added_at.get_or_insert_with(SystemTime::now);
+        self.undo = None;
         self.inner.push_front(song);
     }
 
-    /// Shuffles the queue
+    /// Shuffles the queue.
+    /// In fair mode the order of requesters is kept round-robin
     pub fn shuffle(&mut self) {
-        self.inner.shuffle()
+        self.save_undo();
+        self.inner.shuffle();
+        self.restore_rounds();
+    }
+
+    /// Shuffles the queue so that songs of the same requester are spread across it
+    /// and songs of the same artist are spread among the songs of a requester.
+    /// In fair mode the order of requesters is kept round-robin
+    pub fn smart_shuffle(&mut self) {
+        self.save_undo();
+        self.inner
+            .smart_shuffle_nested(|s| s.requester, |s| s.author.to_lowercase());
+        self.restore_rounds();
+    }
+
+    /// Shuffles the queue with the given mode
+    pub fn shuffle_with_mode(&mut self, mode: ShuffleMode) {
+        match mode {
+            ShuffleMode::Off => {}
+            ShuffleMode::Random => self.shuffle(),
+            ShuffleMode::Smart => self.smart_shuffle(),
+        }
+    }
+
+    /// Sorts the queue by the rounds of the songs in fair mode
+    /// keeping the order of songs in the same round
+    fn restore_rounds(&mut self) {
+        if self.fair {
+            let rounds = self.rounds();
+            let mut entries: Vec<(usize, Song)> =
+                rounds.into_iter().zip(self.inner.drain(..)).collect();
+            entries.sort_by_key(|(round, _)| *round);
+            self.inner = entries.into_iter().map(|(_, s)| s).collect();
+        }
+    }
+
+    /// Returns if the queue plays songs of different requesters round-robin
+    pub fn is_fair(&self) -> bool {
+        self.fair
+    }
+
+    /// Sets if the queue plays songs of different requesters round-robin
+    pub fn set_fair(&mut self, fair: bool) {
+        self.fair = fair;
+    }
+
+    /// Returns the round of each queued song. The round of a song
+    /// is the number of songs of the same requester played before it
+    /// including the current one
+    fn rounds(&self) -> Vec<usize> {
+        let mut counts: HashMap<Option<UserId>, usize> = HashMap::new();
+
+        if let Some(current) = &self.current {
+            counts.insert(current.requester, 1);
+        }
+        self.inner
+            .iter()
+            .map(|song| {
+                let count = counts.entry(song.requester).or_insert(0);
+                *count += 1;
+                *count - 1
+            })
+            .collect()
     }
 
     /// Returns a reference to the inner deque
@@ -42,11 +153,99 @@ impl MusicQueue {
         &self.inner
     }
 
-    /// Returns the next song from the queue
+    /// Returns the estimated time until each queued song starts playing
+    /// given the remaining time of the current song.
+    /// The estimate is unknown for all songs after one with an unknown duration
+    pub fn start_offsets(&self, remaining: Option<Duration>) -> Vec<Option<Duration>> {
+        let mut offset = remaining;
+
+        self.inner
+            .iter()
+            .map(|song| {
+                let start = offset;
+                offset = offset.zip(song.duration()).map(|(o, d)| o + d);
+                start
+            })
+            .collect()
+    }
+
+    /// Returns the estimated time until the queue has finished playing
+    pub fn remaining_duration(&self, remaining: Option<Duration>) -> Option<Duration> {
+        self.inner
+            .iter()
+            .try_fold(remaining?, |total, song| Some(total + song.duration()?))
+    }
+
+    /// Returns the next song from the queue respecting the loop mode.
+    /// The returned song needs to be set as the current one with [MusicQueue::set_current]
     pub fn next(&mut self) -> Option<Song> {
+        let current = self.current.take();
+
+        if self.loop_mode == LoopMode::Track && current.is_some() {
+            return current;
+        }
+        if let Some(current) = current {
+            self.add_to_history(current.clone());
+
+            if self.loop_mode == LoopMode::Queue {
+                self.inner.push_back(current);
+            }
+        }
+        // the queue advanced so the stored state would bring back played songs
+        self.undo = None;
+
         self.inner.pop_front()
     }
 
+    /// Puts the last played song in front of the queue followed by the current song.
+    /// Returns false if there's no song in the history
+    pub fn previous(&mut self) -> bool {
+        if let Some(previous) = self.history.pop_back() {
+            self.undo = None;
+
+            if let Some(current) = self.current.take() {
+                self.inner.push_front(current);
+            }
+            self.inner.push_front(previous);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns a reference to the playback history with the oldest song first
+    pub fn history(&self) -> &VecDeque<Song> {
+        &self.history
+    }
+
+    /// Adds a song to the history removing the oldest entry when the limit is reached
+    fn add_to_history(&mut self, song: Song) {
+        if self.history.len() >= HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+        self.history.push_back(song);
+    }
+
+    /// Returns the next song from the queue without repeating the current track
+    pub fn skip(&mut self) -> Option<Song> {
+        if self.loop_mode == LoopMode::Track {
+            if let Some(current) = self.current.take() {
+                self.add_to_history(current);
+            }
+        }
+        self.next()
+    }
+
+    /// Returns the loop mode of the queue
+    pub fn loop_mode(&self) -> LoopMode {
+        self.loop_mode
+    }
+
+    /// Sets the loop mode of the queue
+    pub fn set_loop_mode(&mut self, mode: LoopMode) {
+        self.loop_mode = mode;
+    }
+
     /// Sets the currently playing song
     pub fn set_current(&mut self, song: Song) {
         self.current = Some(song)
@@ -59,35 +258,222 @@ impl MusicQueue {
 
     /// Clears the queue
     pub fn clear(&mut self) {
+        if self.inner.is_empty() {
+            return;
+        }
+        self.save_undo();
         self.inner.clear();
     }
 
     /// Moves a song to a new position
     pub fn move_position(&mut self, index: usize, new_index: usize) {
         if let Some(song) = self.inner.remove(index) {
-            self.inner.insert(new_index, song);
+            self.undo = None;
+            self.inner.insert(new_index.min(self.inner.len()), song);
         }
     }
 
     /// Removes a song from the queue
     pub fn remove(&mut self, index: usize) {
-        self.inner.remove(index);
+        self.remove_all(&[index]);
+    }
+
+    /// Removes the songs at the given positions.
+    /// Returns the removed songs
+    pub fn remove_all(&mut self, indices: &[usize]) -> Vec<Song> {
+        if !indices.iter().any(|i| *i < self.inner.len()) {
+            return Vec::new();
+        }
+        self.save_undo();
+        remove_indices(&mut self.inner, indices)
+    }
+
+    /// Removes all songs matching the predicate.
+    /// Returns the removed songs
+    pub fn remove_where<F: Fn(&Song) -> bool>(&mut self, predicate: F) -> Vec<Song> {
+        let indices: Vec<usize> = self
+            .inner
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| predicate(s))
+            .map(|(i, _)| i)
+            .collect();
+
+        self.remove_all(&indices)
+    }
+
+    /// Removes songs that have the same url as an earlier song in the queue.
+    /// Returns the removed songs
+    pub fn dedupe(&mut self) -> Vec<Song> {
+        let previous = self.inner.clone();
+        let removed = dedupe_by_key(&mut self.inner, |s| {
+            s.url
+                .clone()
+                .unwrap_or_else(|| format!("{} - {}", s.author, s.title).to_lowercase())
+        });
+        if !removed.is_empty() {
+            self.undo = Some(previous);
+        }
+
+        removed
+    }
+
+    /// Removes all songs before the given position so that
+    /// the song at that position is the next one.
+    /// Returns false if there's no such song
+    pub fn skip_to(&mut self, index: usize) -> bool {
+        if index >= self.inner.len() {
+            return false;
+        }
+        self.save_undo();
+        self.inner.drain(..index);
+
+        true
+    }
+
+    /// Restores the queue to the state before the last clear, shuffle or removal.
+    /// Returns false if there's nothing to undo.
+    /// Adding, moving or playing songs discards the stored state
+    pub fn undo(&mut self) -> bool {
+        if let Some(entries) = self.undo.take() {
+            self.inner = entries;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Stores the current state of the queue to be restored with [MusicQueue::undo]
+    fn save_undo(&mut self) {
+        self.undo = Some(self.inner.clone());
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LoopMode {
+    Off,
+    Track,
+    Queue,
+}
+
+impl LoopMode {
+    /// Returns the mode that follows this one when cycling through all modes
+    pub fn cycle(&self) -> Self {
+        match self {
+            Self::Off => Self::Track,
+            Self::Track => Self::Queue,
+            Self::Queue => Self::Off,
+        }
+    }
+
+    /// Returns the emoji representing the mode
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            Self::Off => "➡️",
+            Self::Track => "🔂",
+            Self::Queue => "🔁",
+        }
+    }
+}
+
+impl Display for LoopMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Off => write!(f, "off"),
+            Self::Track => write!(f, "track"),
+            Self::Queue => write!(f, "queue"),
+        }
     }
 }
 
-#[derive(Clone, Debug)]
+impl FromStr for LoopMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "off" | "none" => Ok(Self::Off),
+            "track" | "song" | "one" => Ok(Self::Track),
+            "queue" | "all" => Ok(Self::Queue),
+            _ => Err(format!("Invalid loop mode '{}'", s)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShuffleMode {
+    Off,
+    Random,
+    Smart,
+}
+
+impl Display for ShuffleMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Off => write!(f, "off"),
+            Self::Random => write!(f, "random"),
+            Self::Smart => write!(f, "smart"),
+        }
+    }
+}
+
+impl FromStr for ShuffleMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "off" | "false" | "none" => Ok(Self::Off),
+            "random" | "true" | "on" => Ok(Self::Random),
+            "smart" => Ok(Self::Smart),
+            _ => Err(format!("Invalid shuffle mode '{}'", s)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum SongSource {
     Spotify(Track),
+    SpotifyEpisode(String),
     YouTube(String),
+    Direct(String),
+    Extractor { url: String, site: String },
 }
 
-#[derive(Clone, Debug)]
+impl SongSource {
+    /// The name of the site the song is from
+    pub fn site_name(&self) -> Option<&str> {
+        match self {
+            Self::Spotify(_) | Self::SpotifyEpisode(_) => Some("Spotify"),
+            Self::YouTube(_) => Some("YouTube"),
+            Self::Direct(_) => None,
+            Self::Extractor { site, .. } => Some(site.as_str()),
+        }
+    }
+
+    /// The domain of the site the song is from
+    pub fn site_domain(&self) -> Option<String> {
+        match self {
+            Self::Spotify(_) | Self::SpotifyEpisode(_) => Some("open.spotify.com".to_string()),
+            Self::YouTube(_) => Some("youtube.com".to_string()),
+            Self::Direct(url) | Self::Extractor { url, .. } => get_domain_for_url(url),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Song {
     pub(crate) url: Option<String>,
     pub(crate) title: String,
     pub(crate) author: String,
     pub(crate) thumbnail: Option<String>,
     pub(crate) source: SongSource,
+    #[serde(default)]
+    pub(crate) autoplay: bool,
+    #[serde(default)]
+    pub(crate) requester: Option<UserId>,
+    #[serde(default)]
+    pub(crate) added_at: Option<SystemTime>,
+    #[serde(default)]
+    pub(crate) duration: Option<Duration>,
 }
 
 impl Song {
@@ -95,6 +481,15 @@ impl Song {
     /// fetched when not available
     pub async fn url(&mut self) -> Option<String> {
         if let Some(url) = self.url.clone() {
+            Some(url)
+        } else if let SongSource::SpotifyEpisode(_) = &self.source {
+            tracing::debug!("Lazy fetching audio of podcast episode");
+            let url = find_episode_audio(&self.author, &self.title)
+                .await
+                .map_err(|e| tracing::error!("Failed to find podcast episode: {:?}", e))
+                .ok()??;
+            self.url = Some(url.clone());
+
             Some(url)
         } else {
             tracing::debug!("Lazy fetching video for title");
@@ -127,6 +522,51 @@ impl Song {
     pub fn source(&self) -> &SongSource {
         &self.source
     }
+
+    /// Creates a song for a podcast episode of the given show
+    pub fn from_episode(episode: EpisodeSimplified, show: String) -> Self {
+        Self {
+            title: episode.name,
+            author: show,
+            url: None,
+            thumbnail: episode.images.first().map(|i| i.url.clone()),
+            source: SongSource::SpotifyEpisode(episode.id),
+            autoplay: false,
+            requester: None,
+            added_at: None,
+            duration: Some(episode.duration),
+        }
+    }
+
+    /// Returns if the song was added by autoplay
+    pub fn is_autoplay(&self) -> bool {
+        self.autoplay
+    }
+
+    /// The user that requested the song
+    pub fn requester(&self) -> Option<UserId> {
+        self.requester
+    }
+
+    /// Sets the user that requested the song
+    pub fn set_requester(&mut self, user_id: UserId) {
+        self.requester = Some(user_id);
+    }
+
+    /// The time the song was added to the queue
+    pub fn added_at(&self) -> Option<SystemTime> {
+        self.added_at
+    }
+
+    /// The duration of the song if known
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
+    /// Sets the duration of the song
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = Some(duration);
+    }
 }
 
 impl From<VideoInformation> for Song {
@@ -137,6 +577,10 @@ impl From<VideoInformation> for Song {
             author: info.uploader,
             thumbnail: info.thumbnail,
             source: SongSource::YouTube(info.webpage_url),
+            autoplay: false,
+            requester: None,
+            added_at: None,
+            duration: info.duration.map(Duration::from_secs_f64),
         }
     }
 }
@@ -149,6 +593,33 @@ impl From<PlaylistEntry> for Song {
             author: entry.uploader,
             thumbnail: None,
             source: SongSource::YouTube(format!("https://www.youtube.com/watch?v={}", entry.url)),
+            autoplay: false,
+            requester: None,
+            added_at: None,
+            duration: entry.duration.map(Duration::from_secs_f64),
+        }
+    }
+}
+
+impl From<ExtractorInformation> for Song {
+    fn from(info: ExtractorInformation) -> Self {
+        let url = info.webpage_url.or(info.url).unwrap_or_default();
+        let site = info
+            .extractor_key
+            .or(info.ie_key)
+            .or_else(|| get_domain_for_url(&url))
+            .unwrap_or_default();
+
+        Self {
+            url: Some(url.clone()),
+            title: info.title.unwrap_or_else(|| url.clone()),
+            author: info.artist.or(info.uploader).unwrap_or_default(),
+            thumbnail: info.thumbnail,
+            source: SongSource::Extractor { url, site },
+            autoplay: false,
+            requester: None,
+            added_at: None,
+            duration: info.duration.map(Duration::from_secs_f64),
         }
     }
 }
@@ -166,7 +637,27 @@ impl From<Track> for Song {
                 .join(" & "),
             url: None,
             thumbnail: None,
+            duration: Some(track.duration),
             source: SongSource::Spotify(track),
+            autoplay: false,
+            requester: None,
+            added_at: None,
+        }
+    }
+}
+
+impl From<Episode> for Song {
+    fn from(episode: Episode) -> Self {
+        Self {
+            title: episode.name,
+            author: episode.show.name,
+            url: None,
+            thumbnail: episode.images.first().map(|i| i.url.clone()),
+            source: SongSource::SpotifyEpisode(episode.id),
+            autoplay: false,
+            requester: None,
+            added_at: None,
+            duration: Some(episode.duration),
         }
     }
 }
@@ -179,6 +670,10 @@ impl From<YoutubeSong> for Song {
             url: Some(song.url.clone()),
             thumbnail: None,
             source: SongSource::YouTube(song.url),
+            autoplay: false,
+            requester: None,
+            added_at: None,
+            duration: None,
         }
     }
 }
@@ -191,6 +686,10 @@ impl From<youtube_metadata::VideoInformation> for Song {
             url: Some(i.url.clone()),
             thumbnail: i.thumbnail,
             source: SongSource::YouTube(i.url),
+            autoplay: false,
+            requester: None,
+            added_at: None,
+            duration: None,
         }
     }
 }
@@ -0,0 +1,324 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use bot_coreutils::url::{get_domain_for_url, is_audio, is_remote_http};
+use regex::Regex;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::providers::music::queue::{Song, SongSource};
+use crate::utils::error::{BotError, BotResult};
+
+/// A file format the queue can be exported to and imported from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    Json,
+    M3u,
+    Xspf,
+}
+
+impl ExportFormat {
+    /// Guesses the format from the name of a file
+    pub fn from_filename(name: &str) -> Option<Self> {
+        let extension = name.rsplit('.').next()?;
+
+        match extension.to_lowercase().as_str() {
+            "m3u8" => Some(Self::M3u),
+            ext => ext.parse().ok(),
+        }
+    }
+
+    /// The file extension of the format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::M3u => "m3u",
+            Self::Xspf => "xspf",
+        }
+    }
+}
+
+impl Display for ExportFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = BotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "m3u" => Ok(Self::M3u),
+            "xspf" => Ok(Self::Xspf),
+            _ => Err(BotError::Msg(format!("Unknown format '{}'", s))),
+        }
+    }
+}
+
+/// A song as it is stored in an exported queue
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ExportedSong {
+    pub title: String,
+    pub author: String,
+    pub url: Option<String>,
+    pub source: ExportedSource,
+}
+
+/// The source of an exported song
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ExportedSource {
+    Spotify { id: Option<String> },
+    SpotifyEpisode { id: String },
+    YouTube,
+    Direct,
+    Extractor { site: String },
+}
+
+impl ExportedSong {
+    /// The url used as location in playlist files.
+    /// Spotify songs use the url of the track to keep its id
+    fn location(&self) -> Option<String> {
+        match &self.source {
+            ExportedSource::Spotify { id: Some(id) } => {
+                Some(format!("https://open.spotify.com/track/{}", id))
+            }
+            ExportedSource::SpotifyEpisode { id } => {
+                Some(format!("https://open.spotify.com/episode/{}", id))
+            }
+            _ => self.url.clone(),
+        }
+    }
+
+    /// Creates a song from the exported one if it can be played without
+    /// fetching additional information.
+    /// Urls that aren't remote http(s) urls are rejected as they're passed to ffmpeg and youtube-dl
+    pub fn into_song(self) -> Option<Song> {
+        let url = self.url.filter(|url| is_remote_http(url))?;
+        let source = match self.source {
+            ExportedSource::Direct => SongSource::Direct(url.clone()),
+            ExportedSource::Extractor { site } => SongSource::Extractor {
+                url: url.clone(),
+                site,
+            },
+            _ => SongSource::YouTube(url.clone()),
+        };
+
+        Some(Song {
+            url: Some(url),
+            title: self.title,
+            author: self.author,
+            thumbnail: None,
+            source,
+            autoplay: false,
+            requester: None,
+            added_at: None,
+            duration: None,
+        })
+    }
+
+    /// Creates an exported song from a location of a playlist file.
+    /// Returns none if the location isn't a remote http(s) url
+    fn from_location(location: String, title: String, author: String) -> Option<Self> {
+        lazy_static::lazy_static! {
+            static ref SPOTIFY_TRACK_REGEX: Regex = Regex::new(r"^(https?://)?(www\.|open\.)?spotify\.com/track/(\w+)").unwrap();
+            static ref SPOTIFY_EPISODE_REGEX: Regex = Regex::new(r"^(https?://)?(www\.|open\.)?spotify\.com/episode/(\w+)").unwrap();
+        }
+        if !is_remote_http(&location) {
+            tracing::debug!("Skipping invalid location {}", location);
+            return None;
+        }
+        let song = if let Some(captures) = SPOTIFY_EPISODE_REGEX.captures(&location) {
+            Self {
+                title,
+                author,
+                url: None,
+                source: ExportedSource::SpotifyEpisode {
+                    id: captures[3].to_string(),
+                },
+            }
+        } else if let Some(captures) = SPOTIFY_TRACK_REGEX.captures(&location) {
+            Self {
+                title,
+                author,
+                url: None,
+                source: ExportedSource::Spotify {
+                    id: Some(captures[3].to_string()),
+                },
+            }
+        } else {
+            let domain = get_domain_for_url(&location).unwrap_or_default();
+            let source = if is_audio(&location) {
+                ExportedSource::Direct
+            } else if domain.ends_with("youtube.com") || domain == "youtu.be" {
+                ExportedSource::YouTube
+            } else {
+                ExportedSource::Extractor { site: domain }
+            };
+            Self {
+                title,
+                author,
+                url: Some(location),
+                source,
+            }
+        };
+
+        Some(song)
+    }
+}
+
+impl From<&Song> for ExportedSong {
+    fn from(song: &Song) -> Self {
+        let source = match song.source() {
+            SongSource::Spotify(track) => ExportedSource::Spotify {
+                id: track.id.clone(),
+            },
+            SongSource::SpotifyEpisode(id) => ExportedSource::SpotifyEpisode { id: id.clone() },
+            SongSource::YouTube(_) => ExportedSource::YouTube,
+            SongSource::Direct(_) => ExportedSource::Direct,
+            SongSource::Extractor { site, .. } => ExportedSource::Extractor { site: site.clone() },
+        };
+
+        Self {
+            title: song.title().clone(),
+            author: song.author().clone(),
+            url: song.url.clone(),
+            source,
+        }
+    }
+}
+
+/// Serializes the songs in the given format
+pub fn export_songs(songs: &[Song], format: ExportFormat) -> BotResult<String> {
+    let songs: Vec<ExportedSong> = songs.iter().map(ExportedSong::from).collect();
+
+    match format {
+        ExportFormat::Json => Ok(serde_json::to_string_pretty(&songs)?),
+        ExportFormat::M3u => Ok(export_m3u(&songs)),
+        ExportFormat::Xspf => Ok(export_xspf(&songs)),
+    }
+}
+
+/// Parses songs from the given content in the given format
+pub fn import_songs(content: &str, format: ExportFormat) -> BotResult<Vec<ExportedSong>> {
+    match format {
+        ExportFormat::Json => Ok(serde_json::from_str(content)?),
+        ExportFormat::M3u => Ok(import_m3u(content)),
+        ExportFormat::Xspf => Ok(import_xspf(content)),
+    }
+}
+
+/// Creates an extended m3u playlist
+fn export_m3u(songs: &[ExportedSong]) -> String {
+    let mut lines = vec!["#EXTM3U".to_string()];
+
+    for song in songs {
+        if let Some(location) = song.location() {
+            lines.push(format!("#EXTINF:-1,{} - {}", song.author, song.title));
+            lines.push(location);
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Parses an m3u playlist using the extended information if available.
+/// Entries with an invalid location are skipped
+fn import_m3u(content: &str) -> Vec<ExportedSong> {
+    let mut songs = Vec::new();
+    let mut info: Option<(String, String)> = None;
+
+    for line in content.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if let Some(extinf) = line.strip_prefix("#EXTINF:") {
+            let name = extinf.splitn(2, ',').nth(1).unwrap_or_default();
+            info = Some(match name.split_once(" - ") {
+                Some((author, title)) => (title.to_string(), author.to_string()),
+                None => (name.to_string(), String::new()),
+            });
+        } else if !line.starts_with('#') {
+            let (title, author) = info
+                .take()
+                .unwrap_or_else(|| (line.to_string(), String::new()));
+            songs.extend(ExportedSong::from_location(line.to_string(), title, author));
+        }
+    }
+
+    songs
+}
+
+/// Creates a xspf playlist
+fn export_xspf(songs: &[ExportedSong]) -> String {
+    let tracks: Vec<String> = songs
+        .iter()
+        .filter_map(|song| {
+            let location = song.location()?;
+
+            Some(format!(
+                "    <track>\n      <location>{}</location>\n      <title>{}</title>\n      <creator>{}</creator>\n    </track>",
+                escape_xml(&location),
+                escape_xml(&song.title),
+                escape_xml(&song.author)
+            ))
+        })
+        .collect();
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<playlist version=\"1\" xmlns=\"http://xspf.org/ns/0/\">\n  <trackList>\n{}\n  </trackList>\n</playlist>",
+        tracks.join("\n")
+    )
+}
+
+/// Parses the tracks of a xspf playlist.
+/// Tracks with an invalid location are skipped
+fn import_xspf(content: &str) -> Vec<ExportedSong> {
+    lazy_static::lazy_static! {
+        static ref TRACK_REGEX: Regex = Regex::new(r"(?s)<track>(.*?)</track>").unwrap();
+        static ref LOCATION_REGEX: Regex = Regex::new(r"(?s)<location>(.*?)</location>").unwrap();
+        static ref TITLE_REGEX: Regex = Regex::new(r"(?s)<title>(.*?)</title>").unwrap();
+        static ref CREATOR_REGEX: Regex = Regex::new(r"(?s)<creator>(.*?)</creator>").unwrap();
+    }
+    let element = |regex: &Regex, track: &str| {
+        regex
+            .captures(track)
+            .map(|c| unescape_xml(c[1].trim()))
+            .unwrap_or_default()
+    };
+
+    TRACK_REGEX
+        .captures_iter(content)
+        .filter_map(|track| {
+            let location = element(&LOCATION_REGEX, &track[1]);
+
+            if location.is_empty() {
+                return None;
+            }
+            ExportedSong::from_location(
+                location,
+                element(&TITLE_REGEX, &track[1]),
+                element(&CREATOR_REGEX, &track[1]),
+            )
+        })
+        .collect()
+}
+
+/// Escapes the special characters of xml
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Replaces xml entities with the characters they represent
+pub(crate) fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
@@ -0,0 +1,151 @@
+use std::time::Duration;
+
+use aspotify::Track;
+use bot_database::Database;
+use futures::stream::{self, Stream, StreamExt};
+use serenity::client::Context;
+use serenity::model::id::GuildId;
+use youtube_metadata::{get_video_information, VideoInformation};
+
+use crate::providers::music::queue::{Song, SongSource};
+use crate::providers::music::youtube_dl;
+use crate::providers::settings::{get_setting, Setting};
+use crate::utils::context_data::get_database_from_context;
+use crate::utils::error::BotResult;
+
+/// The number of songs resolved at the same time if not configured otherwise
+pub static DEFAULT_CONCURRENCY: usize = 4;
+static MAX_CONCURRENCY: usize = 16;
+
+/// Resolves spotify tracks to their stored youtube videos
+/// with a limited number of lookups running at the same time
+#[derive(Clone)]
+pub struct SongResolver {
+    database: Database,
+    concurrency: usize,
+}
+
+impl SongResolver {
+    /// Creates a resolver with the configured concurrency of the guild
+    pub async fn for_guild(ctx: &Context, guild_id: GuildId) -> BotResult<Self> {
+        let concurrency = get_setting::<usize>(ctx, guild_id, Setting::MusicResolveConcurrency)
+            .await?
+            .unwrap_or(DEFAULT_CONCURRENCY)
+            .max(1)
+            .min(MAX_CONCURRENCY);
+
+        Ok(Self {
+            database: get_database_from_context(ctx).await,
+            concurrency,
+        })
+    }
+
+    /// The number of songs resolved at the same time
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Resolves the given songs returning them in their original order
+    /// as soon as they are ready
+    pub fn resolve(&self, songs: Vec<Song>) -> impl Stream<Item = Song> + Send + 'static {
+        let database = self.database.clone();
+
+        stream::iter(songs)
+            .map(move |song| resolve_song(database.clone(), song))
+            .buffered(self.concurrency)
+    }
+
+    /// Resolves all given songs
+    pub async fn resolve_all(&self, songs: Vec<Song>) -> Vec<Song> {
+        self.resolve(songs).collect().await
+    }
+
+    /// Fetches the durations of the songs where it's unknown
+    pub async fn fetch_durations(&self, songs: Vec<Song>) -> Vec<Song> {
+        stream::iter(songs)
+            .map(fetch_duration)
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+}
+
+/// Fetches the duration of a song with youtube-dl if it's unknown
+async fn fetch_duration(mut song: Song) -> Song {
+    if song.duration().is_some() {
+        return song;
+    }
+    let url = if let Some(url) = song.url.clone() {
+        url
+    } else {
+        return song;
+    };
+
+    match youtube_dl::get_audio_information(&url).await {
+        Ok(information) => {
+            if let Some(duration) = information["duration"].as_f64() {
+                song.set_duration(Duration::from_secs_f64(duration));
+            }
+        }
+        Err(e) => tracing::debug!("Failed to fetch duration of {}: {:?}", url, e),
+    }
+
+    song
+}
+
+/// Assigns the youtube video stored for the track to a spotify song
+async fn resolve_song(database: Database, mut song: Song) -> Song {
+    let track = match song.source() {
+        SongSource::Spotify(track) if song.url.is_none() => track.clone(),
+        _ => return song,
+    };
+
+    match get_youtube_video_for_track(&database, track).await {
+        Ok(Some(video)) => {
+            set_video(&mut song, video.url, video.thumbnail);
+            song
+        }
+        Ok(None) => song,
+        Err(e) => {
+            tracing::error!("Failed to resolve song {}: {:?}", song.title(), e);
+            song
+        }
+    }
+}
+
+/// Sets the url of the youtube video to play for a song while keeping
+/// the information of the original song like its duration and requester
+pub(crate) fn set_video(song: &mut Song, url: String, thumbnail: Option<String>) {
+    song.url = Some(url);
+
+    if song.thumbnail.is_none() {
+        song.thumbnail = thumbnail;
+    }
+}
+
+/// Searches for a matching youtube video for the given track in the local database
+pub async fn get_youtube_video_for_track(
+    database: &Database,
+    track: Track,
+) -> BotResult<Option<VideoInformation>> {
+    tracing::debug!("Trying to find track in database.");
+    if let Some(id) = track.id {
+        let entry = database.get_song(&id).await?;
+
+        if let Some(song) = entry {
+            // check if the video is still available
+            tracing::trace!("Found entry is {:?}", song);
+            if let Ok(info) = get_video_information(&song.url).await {
+                return Ok(Some(info));
+            } else {
+                tracing::debug!("Video '{}' is not available. Deleting entry", song.url);
+                database.delete_song(song.id).await?;
+                return Ok(None);
+            }
+        }
+        Ok(None)
+    } else {
+        tracing::debug!("Track has no ID");
+        Ok(None)
+    }
+}
@@ -9,6 +9,20 @@ pub(crate) struct PlaylistEntry {
     pub url: String,
     pub title: String,
     pub uploader: String,
+    pub duration: Option<f64>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct ExtractorInformation {
+    pub url: Option<String>,
+    pub webpage_url: Option<String>,
+    pub title: Option<String>,
+    pub uploader: Option<String>,
+    pub artist: Option<String>,
+    pub thumbnail: Option<String>,
+    pub duration: Option<f64>,
+    pub extractor_key: Option<String>,
+    pub ie_key: Option<String>,
 }
 
 #[derive(Deserialize, Clone, Debug)]
@@ -19,4 +33,5 @@ pub(crate) struct VideoInformation {
     pub thumbnail: Option<String>,
     pub webpage_url: String,
     pub uploader: String,
+    pub duration: Option<f64>,
 }
@@ -0,0 +1,143 @@
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use serenity::async_trait;
+use songbird::input::error::{Error as InputError, Result as InputResult};
+use songbird::input::restartable::Restart;
+use songbird::input::{children_to_reader, Codec, Container, Input, Metadata, Restartable};
+
+use crate::providers::music::filters::{filter_chain, tempo, AudioFilter};
+use crate::providers::music::queue::{Song, SongSource};
+use crate::providers::music::youtube_dl::get_audio_information;
+
+/// Creates a seekable audio input for the given url with the given filters applied.
+/// Already fetched audio information is used instead of fetching it again.
+/// The information is only kept for restarts if it's `reusable` as
+/// the stream urls returned by youtube-dl expire
+pub async fn create_source(
+    url: String,
+    filters: Vec<AudioFilter>,
+    information: Option<serde_json::Value>,
+    reusable: bool,
+) -> InputResult<Input> {
+    let restartable = Restartable::new(
+        FilteredRestarter {
+            url,
+            filters,
+            information,
+            reusable,
+            stream_url: None,
+        },
+        true,
+    )
+    .await?;
+
+    Ok(restartable.into())
+}
+
+/// Returns the stream information for songs that are played directly from an audio url
+pub fn direct_stream_information(song: &Song) -> Option<serde_json::Value> {
+    if let SongSource::Direct(url) = song.source() {
+        Some(serde_json::json!({
+            "url": url,
+            "title": song.title(),
+            "uploader": song.author(),
+            "duration": song.duration().map(|d| d.as_secs_f64()),
+        }))
+    } else {
+        None
+    }
+}
+
+/// Recreates the ffmpeg process for an url whenever
+/// the track is started or seeked
+struct FilteredRestarter {
+    url: String,
+    filters: Vec<AudioFilter>,
+    information: Option<serde_json::Value>,
+    reusable: bool,
+    stream_url: Option<String>,
+}
+
+impl FilteredRestarter {
+    /// Fetches the direct audio stream url and metadata of the source
+    async fn fetch_stream(&mut self) -> InputResult<(String, Metadata)> {
+        let information = match self.information.take() {
+            Some(information) if self.reusable => {
+                self.information = Some(information.clone());
+                information
+            }
+            Some(information) => information,
+            None => get_audio_information(&self.url).await.map_err(|e| {
+                tracing::error!("Failed to get audio information: {:?}", e);
+                InputError::Metadata
+            })?,
+        };
+        let stream_url = information
+            .get("url")
+            .and_then(|u| u.as_str())
+            .ok_or(InputError::Metadata)?
+            .to_string();
+
+        Ok((stream_url, Metadata::from_ytdl_output(information)))
+    }
+}
+
+#[async_trait]
+impl Restart for FilteredRestarter {
+    async fn call_restart(&mut self, time: Option<Duration>) -> InputResult<Input> {
+        let (stream_url, metadata) = if let Some(url) = self.stream_url.take() {
+            (url, Metadata::default())
+        } else {
+            self.fetch_stream().await?
+        };
+        let mut args = vec![
+            "-reconnect".to_string(),
+            "1".to_string(),
+            "-reconnect_streamed".to_string(),
+            "1".to_string(),
+            "-reconnect_delay_max".to_string(),
+            "5".to_string(),
+        ];
+        if let Some(time) = time {
+            // songbird seeks in the played back timeline which the tempo of the filters stretches
+            args.push("-ss".to_string());
+            args.push(format!("{:.3}", time.as_secs_f64() * tempo(&self.filters)));
+        }
+        args.push("-i".to_string());
+        args.push(stream_url);
+
+        if let Some(chain) = filter_chain(&self.filters) {
+            args.push("-af".to_string());
+            args.push(chain);
+        }
+        args.extend(
+            ["-f", "s16le", "-ac", "2", "-ar", "48000", "-acodec", "pcm_f32le", "-"]
+                .iter()
+                .map(|a| a.to_string()),
+        );
+        tracing::trace!("Running ffmpeg with args {:?}", args);
+
+        let ffmpeg = Command::new("ffmpeg")
+            .args(&args)
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        Ok(Input::new(
+            true,
+            children_to_reader::<f32>(vec![ffmpeg]),
+            Codec::FloatPcm,
+            Container::Raw,
+            Some(metadata),
+        ))
+    }
+
+    async fn lazy_init(&mut self) -> InputResult<(Option<Metadata>, Codec, Container)> {
+        let (stream_url, metadata) = self.fetch_stream().await?;
+        self.stream_url = Some(stream_url);
+
+        Ok((Some(metadata), Codec::FloatPcm, Container::Raw))
+    }
+}
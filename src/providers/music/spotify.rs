@@ -1,7 +1,19 @@
-use aspotify::{Client, ClientCredentials, ItemType, PlaylistItemType, Track};
+use aspotify::{
+    AlbumGroup, Artist, Client, ClientCredentials, CountryCode, Episode, EpisodeSimplified,
+    ItemType, Market, PlaylistItemType, Track,
+};
 
 use crate::utils::error::{BotError, BotResult};
 
+/// The country used for requests that depend on the market
+static COUNTRY: CountryCode = CountryCode::USA;
+
+/// An item of a spotify playlist
+pub enum SpotifyItem {
+    Track(Track),
+    Episode(Episode),
+}
+
 pub struct SpotifyApi {
     client: Client,
 }
@@ -38,8 +50,64 @@ impl SpotifyApi {
         Ok(tracks.items.into_iter().next())
     }
 
-    /// Returns the songs for a playlist
-    pub async fn get_songs_in_playlist(&self, url: &str) -> BotResult<Vec<Track>> {
+    /// Searches for an artist on spotify
+    pub async fn search_for_artist(&self, name: &str) -> BotResult<Option<Artist>> {
+        tracing::debug!("Searching for artist '{}' on spotify", name);
+        let types = vec![ItemType::Artist];
+        let result = self
+            .client
+            .search()
+            .search(name, types, false, 1, 0, None)
+            .await?;
+        tracing::trace!("Result is {:?}", result);
+        let artists = result
+            .data
+            .artists
+            .ok_or(BotError::from("Failed to get search spotify results"))?;
+
+        Ok(artists.items.into_iter().next())
+    }
+
+    /// Returns artists that are similar to the given one
+    pub async fn get_related_artists(&self, artist_id: &str) -> BotResult<Vec<Artist>> {
+        tracing::debug!("Fetching related artists for '{}'", artist_id);
+        let artists = self
+            .client
+            .artists()
+            .get_related_artists(artist_id)
+            .await?
+            .data;
+        tracing::trace!("Related artists are {:?}", artists);
+
+        Ok(artists)
+    }
+
+    /// Searches for tracks of the given artist
+    pub async fn search_artist_tracks(&self, artist: &str, limit: usize) -> BotResult<Vec<Track>> {
+        tracing::debug!("Searching for tracks of artist '{}'", artist);
+        let types = vec![ItemType::Track];
+        let result = self
+            .client
+            .search()
+            .search(
+                &format!("artist:\"{}\"", artist),
+                types,
+                false,
+                limit,
+                0,
+                None,
+            )
+            .await?;
+        let tracks = result
+            .data
+            .tracks
+            .ok_or(BotError::from("Failed to get search spotify results"))?;
+
+        Ok(tracks.items)
+    }
+
+    /// Returns the songs and podcast episodes for a playlist
+    pub async fn get_songs_in_playlist(&self, url: &str) -> BotResult<Vec<SpotifyItem>> {
         tracing::debug!("Fetching spotify songs from playlist '{}'", url);
         let id = self.get_id_for_url(url)?;
         let mut playlist_tracks = Vec::new();
@@ -70,7 +138,7 @@ impl SpotifyApi {
         id: &str,
         limit: usize,
         offset: usize,
-    ) -> BotResult<Vec<Track>> {
+    ) -> BotResult<Vec<SpotifyItem>> {
         tracing::trace!(
             "Fetching songs from spotify playlist: limit {}, offset {}",
             limit,
@@ -83,16 +151,15 @@ impl SpotifyApi {
             .await?
             .data;
 
-        let tracks: Vec<Track> = page
+        let tracks: Vec<SpotifyItem> = page
             .items
             .into_iter()
             .filter_map(|item| item.item)
-            .filter_map(|t| match t {
-                PlaylistItemType::Track(t) => Some(t),
-                PlaylistItemType::Episode(_) => None,
+            .map(|t| match t {
+                PlaylistItemType::Track(t) => SpotifyItem::Track(t),
+                PlaylistItemType::Episode(e) => SpotifyItem::Episode(e),
             })
             .collect();
-        tracing::trace!("Tracks are {:?}", tracks);
 
         Ok(tracks)
     }
@@ -101,7 +168,111 @@ impl SpotifyApi {
     pub async fn get_songs_in_album(&self, url: &str) -> BotResult<Vec<Track>> {
         tracing::debug!("Fetching songs for spotify album '{}'", url);
         let id = self.get_id_for_url(url)?;
-        let album = self.client.albums().get_album(&*id, None).await?.data;
+
+        self.get_album_tracks(&id).await
+    }
+
+    /// Returns the top tracks of the artist with the given url
+    pub async fn get_artist_top_tracks(&self, url: &str) -> BotResult<Vec<Track>> {
+        tracing::debug!("Fetching top tracks for spotify artist '{}'", url);
+        let id = self.get_id_for_url(url)?;
+        let tracks = self
+            .client
+            .artists()
+            .get_artist_top(&*id, Market::Country(COUNTRY))
+            .await?
+            .data;
+        tracing::trace!("Tracks are {:?}", tracks);
+
+        Ok(tracks)
+    }
+
+    /// Returns the tracks of the latest albums and singles of the artist with the given url.
+    /// At most `album_limit` albums are fetched and at most `track_limit` tracks are returned
+    pub async fn get_artist_discography(
+        &self,
+        url: &str,
+        album_limit: usize,
+        track_limit: usize,
+    ) -> BotResult<Vec<Track>> {
+        tracing::debug!("Fetching discography for spotify artist '{}'", url);
+        let id = self.get_id_for_url(url)?;
+        let groups = [AlbumGroup::Album, AlbumGroup::Single];
+        let mut album_ids = Vec::new();
+        let mut offset = 0;
+
+        while offset < album_limit {
+            let page_size = (album_limit - offset).min(50);
+            let page = self
+                .client
+                .artists()
+                .get_artist_albums(&*id, Some(&groups), page_size, offset, Some(COUNTRY))
+                .await?
+                .data;
+            let count = page.items.len();
+            album_ids.extend(page.items.into_iter().filter_map(|a| a.album.id));
+
+            if count < page_size {
+                break;
+            }
+            offset += page_size;
+        }
+        let mut tracks = Vec::new();
+
+        for album_id in album_ids {
+            if tracks.len() >= track_limit {
+                break;
+            }
+            tracks.append(&mut self.get_album_tracks(&album_id).await?);
+        }
+        tracks.truncate(track_limit);
+        tracing::debug!("{} Songs found for spotify artist '{}'", tracks.len(), url);
+
+        Ok(tracks)
+    }
+
+    /// Returns the name of the podcast show with the given url and its latest episodes
+    pub async fn get_show_episodes(
+        &self,
+        url: &str,
+        limit: usize,
+    ) -> BotResult<(String, Vec<EpisodeSimplified>)> {
+        tracing::debug!("Fetching episodes for spotify show '{}'", url);
+        let id = self.get_id_for_url(url)?;
+        let show = self
+            .client
+            .shows()
+            .get_show(&*id, Some(Market::Country(COUNTRY)))
+            .await?
+            .data;
+        let episodes = self
+            .client
+            .shows()
+            .get_show_episodes(&*id, limit, 0, Some(Market::Country(COUNTRY)))
+            .await?
+            .data
+            .items;
+
+        Ok((show.name, episodes))
+    }
+
+    /// Returns the podcast episode for a given spotify url
+    pub async fn get_episode_for_url(&self, url: &str) -> BotResult<Episode> {
+        tracing::debug!("Getting episode for {}", url);
+        let id = self.get_id_for_url(url)?;
+        let episode = self
+            .client
+            .episodes()
+            .get_episode(&*id, Some(Market::Country(COUNTRY)))
+            .await?
+            .data;
+
+        Ok(episode)
+    }
+
+    /// Returns the tracks of the album with the given id
+    async fn get_album_tracks(&self, id: &str) -> BotResult<Vec<Track>> {
+        let album = self.client.albums().get_album(id, None).await?.data;
         tracing::trace!("Album is {:?}", album);
 
         let simple_tracks: Vec<String> = album
@@ -8,8 +8,10 @@ use futures::FutureExt;
 use bot_coreutils::process::run_command_async;
 
 use crate::providers::music::queue::Song;
-use crate::providers::music::responses::{PlaylistEntry, VideoInformation};
-use crate::utils::error::BotResult;
+use crate::providers::music::responses::{
+    ExtractorInformation, PlaylistEntry, VideoInformation,
+};
+use crate::utils::error::{BotError, BotResult};
 use bot_coreutils::string::enquote;
 
 static THREAD_LIMIT: u8 = 64;
@@ -17,8 +19,16 @@ static THREAD_LIMIT: u8 = 64;
 /// Returns a list of youtube videos for a given url
 pub(crate) async fn get_videos_for_playlist(url: &str) -> BotResult<Vec<PlaylistEntry>> {
     tracing::debug!("Getting playlist information for {}", url);
-    let output =
-        youtube_dl(&["--no-warnings", "--flat-playlist", "--dump-json", "-i", url]).await?;
+    check_url(url)?;
+    let output = youtube_dl(&[
+        "--no-warnings",
+        "--flat-playlist",
+        "--dump-json",
+        "-i",
+        "--",
+        url,
+    ])
+    .await?;
 
     let videos = output
         .lines()
@@ -28,6 +38,29 @@ pub(crate) async fn get_videos_for_playlist(url: &str) -> BotResult<Vec<Playlist
     Ok(videos)
 }
 
+/// Returns the tracks for an url of any site supported by youtube-dl.
+/// Playlists are returned as flat entries while single tracks contain all information
+pub(crate) async fn get_extractor_information(url: &str) -> BotResult<Vec<ExtractorInformation>> {
+    tracing::debug!("Getting extractor information for {}", url);
+    check_url(url)?;
+    let output = youtube_dl(&[
+        "--no-warnings",
+        "--flat-playlist",
+        "--dump-json",
+        "-i",
+        "--",
+        url,
+    ])
+    .await?;
+
+    let entries = output
+        .lines()
+        .filter_map(|l| serde_json::from_str::<ExtractorInformation>(l).ok())
+        .collect();
+
+    Ok(entries)
+}
+
 /// Searches for a video
 pub(crate) async fn search_video_information(query: String) -> BotResult<Option<VideoInformation>> {
     tracing::debug!("Searching for video '{}'", query);
@@ -43,6 +76,55 @@ pub(crate) async fn search_video_information(query: String) -> BotResult<Option<
     Ok(information)
 }
 
+/// Searches for the given number of videos
+pub(crate) async fn search_videos(query: &str, count: usize) -> BotResult<Vec<VideoInformation>> {
+    tracing::debug!("Searching for {} videos '{}'", count, query);
+    let output = youtube_dl(&[
+        "--no-warnings",
+        "--dump-json",
+        "-i",
+        format!("ytsearch{}:{}", count, enquote(query)).as_str(),
+    ])
+    .await?;
+    let videos = output
+        .lines()
+        .filter_map(|l| serde_json::from_str::<VideoInformation>(l).ok())
+        .collect();
+
+    Ok(videos)
+}
+
+/// Returns the information of the best audio format for the given url
+/// including the direct stream url
+pub(crate) async fn get_audio_information(url: &str) -> BotResult<serde_json::Value> {
+    tracing::debug!("Getting audio information for {}", url);
+    check_url(url)?;
+    let output = youtube_dl(&[
+        "--no-warnings",
+        "--dump-json",
+        "--no-playlist",
+        "-f",
+        "bestaudio/best",
+        "--",
+        url,
+    ])
+    .await?;
+    let information = serde_json::from_str(&*output)?;
+
+    Ok(information)
+}
+
+/// Rejects urls that youtube-dl could interpret as options.
+/// Urls are also passed after `--` so this is a second line of defense
+fn check_url(url: &str) -> BotResult<()> {
+    if url.trim_start().starts_with('-') {
+        tracing::warn!("Rejecting url '{}' that looks like an option", url);
+        Err(BotError::CliInject)
+    } else {
+        Ok(())
+    }
+}
+
 /// Searches songs on youtube in parallel
 #[allow(dead_code)]
 async fn parallel_search_youtube(song_names: Vec<String>) -> Vec<Song> {
@@ -10,12 +10,32 @@ pub static ALL_SETTINGS: &[Setting] = &[
     Setting::MusicAutoShuffle,
     Setting::BotAutoDelete,
     Setting::MusicDjRole,
+    Setting::MusicVolume,
+    Setting::MusicAutoplay,
+    Setting::MusicVoteskipRatio,
+    Setting::MusicFairQueue,
+    Setting::MusicMaxQueueLength,
+    Setting::MusicMaxUserSongs,
+    Setting::MusicMaxDuration,
+    Setting::MusicAllowPlaylists,
+    Setting::MusicCrossfade,
+    Setting::MusicResolveConcurrency,
 ];
 
 #[derive(Clone, Debug)]
 pub enum Setting {
     MusicAutoShuffle,
     MusicDjRole,
+    MusicVolume,
+    MusicAutoplay,
+    MusicVoteskipRatio,
+    MusicFairQueue,
+    MusicMaxQueueLength,
+    MusicMaxUserSongs,
+    MusicMaxDuration,
+    MusicAllowPlaylists,
+    MusicCrossfade,
+    MusicResolveConcurrency,
     BotAutoDelete,
 }
 
@@ -25,6 +45,16 @@ impl ToString for Setting {
             Self::MusicAutoShuffle => "music.autoshuffle".to_string(),
             Self::BotAutoDelete => "bot.autodelete".to_string(),
             Self::MusicDjRole => "music.dj-role".to_string(),
+            Self::MusicVolume => "music.volume".to_string(),
+            Self::MusicAutoplay => "music.autoplay".to_string(),
+            Self::MusicVoteskipRatio => "music.voteskip-ratio".to_string(),
+            Self::MusicFairQueue => "music.fair-queue".to_string(),
+            Self::MusicMaxQueueLength => "music.max-queue-length".to_string(),
+            Self::MusicMaxUserSongs => "music.max-user-songs".to_string(),
+            Self::MusicMaxDuration => "music.max-duration".to_string(),
+            Self::MusicAllowPlaylists => "music.allow-playlists".to_string(),
+            Self::MusicCrossfade => "music.crossfade".to_string(),
+            Self::MusicResolveConcurrency => "music.resolve-concurrency".to_string(),
         }
     }
 }
@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod queue_tests;
+#[cfg(test)]
+mod resolver_tests;
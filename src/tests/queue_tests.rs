@@ -0,0 +1,218 @@
+use crate::providers::music::queue::{LoopMode, MusicQueue, Song, SongSource};
+use serenity::model::id::UserId;
+
+fn song(title: &str, requester: u64) -> Song {
+    let url = format!("https://www.youtube.com/watch?v={}", title);
+
+    Song {
+        url: Some(url.clone()),
+        title: title.to_string(),
+        author: "artist".to_string(),
+        thumbnail: None,
+        source: SongSource::YouTube(url),
+        autoplay: false,
+        requester: Some(UserId(requester)),
+        added_at: None,
+        duration: None,
+    }
+}
+
+fn queue_with(songs: &[(&str, u64)]) -> MusicQueue {
+    let mut queue = MusicQueue::new();
+
+    for (title, requester) in songs {
+        queue.add(song(title, *requester));
+    }
+
+    queue
+}
+
+fn fair_queue_with(songs: &[(&str, u64)]) -> MusicQueue {
+    let mut queue = MusicQueue::new();
+    queue.set_fair(true);
+
+    for (title, requester) in songs {
+        queue.add(song(title, *requester));
+    }
+
+    queue
+}
+
+fn titles(queue: &MusicQueue) -> Vec<String> {
+    queue.entries().iter().map(|s| s.title().clone()).collect()
+}
+
+#[test]
+fn test_remove_all() {
+    let mut queue = queue_with(&[("a", 1), ("b", 1), ("c", 1), ("d", 1)]);
+    let removed = queue.remove_all(&[1, 3, 10]);
+
+    assert_eq!(removed.len(), 2);
+    assert_eq!(titles(&queue), vec!["a", "c"]);
+    assert!(queue.undo());
+    assert_eq!(titles(&queue), vec!["a", "b", "c", "d"]);
+    assert!(!queue.undo());
+}
+
+#[test]
+fn test_remove_nothing_keeps_undo() {
+    let mut queue = queue_with(&[("a", 1), ("b", 1), ("c", 1)]);
+    queue.remove_all(&[0]);
+
+    assert!(queue.remove_all(&[5]).is_empty());
+    assert!(queue.remove_where(|_| false).is_empty());
+    assert!(queue.undo());
+    assert_eq!(titles(&queue), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_remove_where() {
+    let mut queue = queue_with(&[("a", 1), ("b", 2), ("c", 1), ("d", 3)]);
+    let removed = queue.remove_where(|s| s.requester() == Some(UserId(1)));
+
+    assert_eq!(removed.len(), 2);
+    assert_eq!(titles(&queue), vec!["b", "d"]);
+}
+
+#[test]
+fn test_dedupe() {
+    let mut queue = queue_with(&[("a", 1), ("b", 2), ("a", 2), ("c", 1), ("b", 1)]);
+    let mut unresolved = song("e", 1);
+    unresolved.url = None;
+    queue.add(unresolved.clone());
+    queue.add(unresolved);
+
+    assert_eq!(queue.dedupe().len(), 3);
+    assert_eq!(titles(&queue), vec!["a", "b", "c", "e"]);
+    assert!(queue.dedupe().is_empty());
+    assert!(queue.undo());
+    assert_eq!(queue.entries().len(), 7);
+}
+
+#[test]
+fn test_skip_to() {
+    let mut queue = queue_with(&[("a", 1), ("b", 1), ("c", 1), ("d", 1)]);
+
+    assert!(!queue.skip_to(4));
+    assert_eq!(queue.entries().len(), 4);
+    assert!(queue.skip_to(2));
+    assert_eq!(titles(&queue), vec!["c", "d"]);
+    assert!(queue.undo());
+    assert_eq!(titles(&queue), vec!["a", "b", "c", "d"]);
+}
+
+#[test]
+fn test_undo_clear() {
+    let mut queue = queue_with(&[("a", 1), ("b", 1)]);
+    queue.clear();
+
+    assert!(queue.entries().is_empty());
+    assert!(queue.undo());
+    assert_eq!(titles(&queue), vec!["a", "b"]);
+}
+
+#[test]
+fn test_undo_is_discarded_on_changes() {
+    let mut queue = queue_with(&[("a", 1), ("b", 1), ("c", 1)]);
+    queue.remove(0);
+    queue.add(song("d", 1));
+    assert!(!queue.undo());
+
+    queue.remove(0);
+    queue.move_position(1, 0);
+    assert!(!queue.undo());
+
+    queue.remove(0);
+    let next = queue.next();
+    assert!(next.is_some());
+    assert!(!queue.undo());
+}
+
+#[test]
+fn test_add_without_fair_mode() {
+    let queue = queue_with(&[("a1", 1), ("a2", 1), ("b1", 2)]);
+
+    assert_eq!(titles(&queue), vec!["a1", "a2", "b1"]);
+}
+
+#[test]
+fn test_add_fair() {
+    let queue = fair_queue_with(&[
+        ("a1", 1),
+        ("a2", 1),
+        ("a3", 1),
+        ("b1", 2),
+        ("c1", 3),
+        ("b2", 2),
+    ]);
+
+    assert_eq!(titles(&queue), vec!["a1", "b1", "c1", "a2", "b2", "a3"]);
+}
+
+#[test]
+fn test_add_fair_counts_current_song() {
+    let mut queue = fair_queue_with(&[("a1", 1), ("b1", 2)]);
+    let current = queue.next().unwrap();
+    queue.set_current(current);
+
+    queue.add(song("a2", 1));
+    queue.add(song("a3", 1));
+    queue.add(song("b2", 2));
+
+    assert_eq!(titles(&queue), vec!["b1", "a2", "b2", "a3"]);
+}
+
+#[test]
+fn test_shuffle_fair_keeps_rounds() {
+    let mut queue = fair_queue_with(&[("a1", 1), ("a2", 1), ("b1", 2), ("b2", 2)]);
+    queue.shuffle();
+
+    let requesters: Vec<_> = queue.entries().iter().map(|s| s.requester()).collect();
+    assert_ne!(requesters[0], requesters[1]);
+    assert_ne!(requesters[2], requesters[3]);
+}
+
+#[test]
+fn test_skip_looped_track_adds_it_to_history() {
+    let mut queue = queue_with(&[("a", 1), ("b", 1)]);
+    let current = queue.next().unwrap();
+    queue.set_current(current);
+    queue.set_loop_mode(LoopMode::Track);
+
+    let skipped = queue.skip().unwrap();
+    assert_eq!(skipped.title(), "b");
+    let history: Vec<&String> = queue.history().iter().map(|s| s.title()).collect();
+    assert_eq!(history, vec!["a"]);
+}
+
+#[test]
+fn test_smart_shuffle_spreads_requesters_without_fair_mode() {
+    let mut queue = queue_with(&[
+        ("a1", 1),
+        ("a2", 1),
+        ("a3", 1),
+        ("b1", 2),
+        ("b2", 2),
+        ("b3", 2),
+    ]);
+    queue.smart_shuffle();
+
+    let requesters: Vec<_> = queue.entries().iter().map(|s| s.requester()).collect();
+    assert!(!queue.is_fair());
+    assert_eq!(requesters.len(), 6);
+    for (previous, next) in requesters.iter().zip(requesters.iter().skip(1)) {
+        assert_ne!(previous, next, "{:?}", titles(&queue));
+    }
+}
+
+#[test]
+fn test_restore_keeps_order_in_fair_mode() {
+    let mut queue = MusicQueue::new();
+    queue.set_fair(true);
+    queue.restore(
+        Some(song("current", 1)),
+        vec![song("a1", 1), song("a2", 1), song("b1", 2)],
+    );
+
+    assert_eq!(titles(&queue), vec!["current", "a1", "a2", "b1"]);
+}
@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use crate::providers::music::queue::{Song, SongSource};
+use crate::providers::music::resolver::set_video;
+use serenity::model::id::UserId;
+
+fn unresolved_song() -> Song {
+    Song {
+        url: None,
+        title: "title".to_string(),
+        author: "artist".to_string(),
+        thumbnail: None,
+        source: SongSource::Direct("https://domain.com/song.mp3".to_string()),
+        autoplay: false,
+        requester: Some(UserId(1)),
+        added_at: None,
+        duration: Some(Duration::from_secs(215)),
+    }
+}
+
+#[test]
+fn test_set_cached_video_keeps_song_information() {
+    let mut song = unresolved_song();
+    let url = "https://www.youtube.com/watch?v=video".to_string();
+    set_video(&mut song, url.clone(), Some("thumbnail".to_string()));
+
+    assert_eq!(song.url, Some(url));
+    assert_eq!(song.title(), "title");
+    assert_eq!(song.duration(), Some(Duration::from_secs(215)));
+    assert_eq!(song.requester(), Some(UserId(1)));
+    assert_eq!(song.thumbnail(), &Some("thumbnail".to_string()));
+}
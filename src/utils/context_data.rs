@@ -16,7 +16,7 @@ pub struct Store;
 
 pub struct StoreData {
     pub minecraft_data_api: minecraft_data_rs::api::Api,
-    pub spotify_api: SpotifyApi,
+    pub spotify_api: Arc<SpotifyApi>,
     pub sauce_nao: SauceNao,
 }
 
@@ -30,7 +30,7 @@ impl StoreData {
 
         Self {
             minecraft_data_api: minecraft_data_rs::api::Api::latest().unwrap(),
-            spotify_api: SpotifyApi::new(),
+            spotify_api: Arc::new(SpotifyApi::new()),
             sauce_nao,
         }
     }